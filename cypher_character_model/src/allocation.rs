@@ -0,0 +1,173 @@
+//! The additional points a new character divides among their stat Pools.
+
+use std::error::Error;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::effort::EffortType;
+
+/// The number of points a new character has to divide among their Pools.
+pub const STARTING_POINTS: u8 = 6;
+
+/// The rules a [`StartingAllocation`] is validated against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AllocationRules {
+    /// The number of points that must be allocated in total.
+    pub total: u8,
+    /// The most points that may go into any single Pool, if limited.
+    pub max_per_pool: Option<u8>,
+}
+
+impl Default for AllocationRules {
+    fn default() -> Self {
+        Self {
+            total: STARTING_POINTS,
+            max_per_pool: None,
+        }
+    }
+}
+
+/// How a new character's additional points are divided among their Pools.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct StartingAllocation {
+    pub might: u8,
+    pub speed: u8,
+    pub intellect: u8,
+}
+
+impl StartingAllocation {
+    pub fn new(might: u8, speed: u8, intellect: u8) -> Self {
+        Self {
+            might,
+            speed,
+            intellect,
+        }
+    }
+
+    /// The points allocated to the given Pool.
+    pub fn points(&self, pool: EffortType) -> u8 {
+        self[pool]
+    }
+
+    /// The total number of points allocated.
+    pub fn total(&self) -> u16 {
+        u16::from(self.might) + u16::from(self.speed) + u16::from(self.intellect)
+    }
+
+    /// Checks this allocation against the given rules.
+    pub fn validate(&self, rules: &AllocationRules) -> Result<(), AllocationError> {
+        if let Some(max) = rules.max_per_pool {
+            for pool in EffortType::iter() {
+                let points = self.points(pool);
+                if points > max {
+                    return Err(AllocationError::PoolOverLimit { pool, points, max });
+                }
+            }
+        }
+
+        let total = self.total();
+        if total != u16::from(rules.total) {
+            return Err(AllocationError::WrongTotal {
+                expected: rules.total,
+                actual: total,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl Index<EffortType> for StartingAllocation {
+    type Output = u8;
+
+    fn index(&self, pool: EffortType) -> &u8 {
+        match pool {
+            EffortType::Might => &self.might,
+            EffortType::Speed => &self.speed,
+            EffortType::Intellect => &self.intellect,
+        }
+    }
+}
+
+impl IndexMut<EffortType> for StartingAllocation {
+    fn index_mut(&mut self, pool: EffortType) -> &mut u8 {
+        match pool {
+            EffortType::Might => &mut self.might,
+            EffortType::Speed => &mut self.speed,
+            EffortType::Intellect => &mut self.intellect,
+        }
+    }
+}
+
+/// The allocation rule that a [`StartingAllocation`] broke.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AllocationError {
+    /// More points were put into a single Pool than the rules allow.
+    PoolOverLimit {
+        pool: EffortType,
+        points: u8,
+        max: u8,
+    },
+    /// The points allocated do not add up to the required total.
+    WrongTotal { expected: u8, actual: u16 },
+}
+
+impl fmt::Display for AllocationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AllocationError::PoolOverLimit { pool, points, max } => write!(
+                f,
+                "{points} points allocated to {pool}, but at most {max} are allowed"
+            ),
+            AllocationError::WrongTotal { expected, actual } => write!(
+                f,
+                "{actual} points allocated, but exactly {expected} must be allocated"
+            ),
+        }
+    }
+}
+
+impl Error for AllocationError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_checks_total() {
+        let rules = AllocationRules::default();
+        assert_eq!(StartingAllocation::new(2, 2, 2).validate(&rules), Ok(()));
+        assert_eq!(
+            StartingAllocation::new(2, 2, 1).validate(&rules),
+            Err(AllocationError::WrongTotal {
+                expected: 6,
+                actual: 5
+            })
+        );
+    }
+
+    #[test]
+    fn pools_can_be_indexed_by_effort_type() {
+        let mut allocation = StartingAllocation::default();
+        for pool in EffortType::iter() {
+            allocation[pool] += 2;
+        }
+        assert_eq!(allocation, StartingAllocation::new(2, 2, 2));
+    }
+
+    #[test]
+    fn validate_checks_per_pool_limit() {
+        let rules = AllocationRules {
+            max_per_pool: Some(4),
+            ..AllocationRules::default()
+        };
+        assert_eq!(
+            StartingAllocation::new(0, 6, 0).validate(&rules),
+            Err(AllocationError::PoolOverLimit {
+                pool: EffortType::Speed,
+                points: 6,
+                max: 4
+            })
+        );
+    }
+}
@@ -0,0 +1,250 @@
+//! Character arcs: personal stories a character works through for XP.
+
+use std::error::Error;
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::id::ArcId;
+
+/// The kind of story an arc tells.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ArcTemplate {
+    Avenge,
+    Build,
+    Creation,
+    Discovery,
+    Justice,
+    Mystery,
+    Protect,
+    Redemption,
+    Rescue,
+    Romance,
+    Custom(String),
+}
+
+impl fmt::Display for ArcTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ArcTemplate::Avenge => "Avenge",
+            ArcTemplate::Build => "Build",
+            ArcTemplate::Creation => "Creation",
+            ArcTemplate::Discovery => "Discovery",
+            ArcTemplate::Justice => "Justice",
+            ArcTemplate::Mystery => "Mystery",
+            ArcTemplate::Protect => "Protect",
+            ArcTemplate::Redemption => "Redemption",
+            ArcTemplate::Rescue => "Rescue",
+            ArcTemplate::Romance => "Romance",
+            ArcTemplate::Custom(name) => name,
+        };
+        f.write_str(name)
+    }
+}
+
+/// The XP awarded for completing each part of an arc.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ArcRewards {
+    pub step: u32,
+    #[cfg_attr(feature = "serde", serde(alias = "climaxSuccess"))]
+    pub climax_success: u32,
+    #[cfg_attr(feature = "serde", serde(alias = "climaxFailure"))]
+    pub climax_failure: u32,
+    pub resolution: u32,
+}
+
+impl Default for ArcRewards {
+    fn default() -> Self {
+        Self {
+            step: 2,
+            climax_success: 4,
+            climax_failure: 2,
+            resolution: 1,
+        }
+    }
+}
+
+/// A middle step of an arc, between its opening and its climax.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ArcStep {
+    pub description: String,
+    pub completed: bool,
+}
+
+/// How an arc's climax turned out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ClimaxOutcome {
+    Success,
+    Failure,
+}
+
+/// Why progress on an arc could not be recorded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ArcError {
+    /// There is no step at the given index.
+    NoSuchStep(usize),
+    /// The step at the given index has already been completed.
+    StepAlreadyCompleted(usize),
+    /// The climax was attempted before every step was completed.
+    StepsIncomplete,
+    /// The climax has already happened.
+    ClimaxAlreadyReached,
+    /// The arc was resolved before its climax.
+    ClimaxNotReached,
+    /// The arc has already been resolved.
+    AlreadyResolved,
+}
+
+impl fmt::Display for ArcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArcError::NoSuchStep(index) => write!(f, "the arc has no step {index}"),
+            ArcError::StepAlreadyCompleted(index) => {
+                write!(f, "step {index} of the arc is already completed")
+            }
+            ArcError::StepsIncomplete => {
+                f.write_str("every step must be completed before the climax")
+            }
+            ArcError::ClimaxAlreadyReached => f.write_str("the arc has already had its climax"),
+            ArcError::ClimaxNotReached => {
+                f.write_str("the arc cannot be resolved before its climax")
+            }
+            ArcError::AlreadyResolved => f.write_str("the arc has already been resolved"),
+        }
+    }
+}
+
+impl Error for ArcError {}
+
+/// A character arc and the character's progress through it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CharacterArc {
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub id: Option<ArcId>,
+    pub template: ArcTemplate,
+    /// What the character set out to do when opening the arc.
+    pub opening: String,
+    pub steps: Vec<ArcStep>,
+    pub climax: Option<ClimaxOutcome>,
+    pub resolved: bool,
+    pub rewards: ArcRewards,
+}
+
+impl CharacterArc {
+    /// Opens a new arc.
+    pub fn open(template: ArcTemplate, opening: impl Into<String>) -> Self {
+        Self {
+            id: None,
+            template,
+            opening: opening.into(),
+            steps: Vec::new(),
+            climax: None,
+            resolved: false,
+            rewards: ArcRewards::default(),
+        }
+    }
+
+    pub fn with_id(mut self, id: ArcId) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn with_rewards(mut self, rewards: ArcRewards) -> Self {
+        self.rewards = rewards;
+        self
+    }
+
+    /// Adds a step to work through before the climax.
+    pub fn add_step(&mut self, description: impl Into<String>) {
+        self.steps.push(ArcStep {
+            description: description.into(),
+            completed: false,
+        });
+    }
+
+    /// Completes the step at the given index, returning the XP earned.
+    pub fn complete_step(&mut self, index: usize) -> Result<u32, ArcError> {
+        let step = self
+            .steps
+            .get_mut(index)
+            .ok_or(ArcError::NoSuchStep(index))?;
+        if step.completed {
+            return Err(ArcError::StepAlreadyCompleted(index));
+        }
+        step.completed = true;
+        Ok(self.rewards.step)
+    }
+
+    /// Records the outcome of the arc's climax, returning the XP earned.
+    pub fn complete_climax(&mut self, outcome: ClimaxOutcome) -> Result<u32, ArcError> {
+        if self.climax.is_some() {
+            return Err(ArcError::ClimaxAlreadyReached);
+        }
+        if self.steps.iter().any(|step| !step.completed) {
+            return Err(ArcError::StepsIncomplete);
+        }
+        self.climax = Some(outcome);
+        Ok(match outcome {
+            ClimaxOutcome::Success => self.rewards.climax_success,
+            ClimaxOutcome::Failure => self.rewards.climax_failure,
+        })
+    }
+
+    /// Resolves the arc after its climax, returning the XP earned.
+    pub fn resolve(&mut self) -> Result<u32, ArcError> {
+        if self.resolved {
+            return Err(ArcError::AlreadyResolved);
+        }
+        if self.climax.is_none() {
+            return Err(ArcError::ClimaxNotReached);
+        }
+        self.resolved = true;
+        Ok(self.rewards.resolution)
+    }
+
+    /// The number of steps completed out of the total.
+    pub fn progress(&self) -> (usize, usize) {
+        let completed = self.steps.iter().filter(|step| step.completed).count();
+        (completed, self.steps.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arc_awards_xp_in_order() {
+        let mut arc = CharacterArc::open(ArcTemplate::Avenge, "Find who burned the village");
+        arc.add_step("Track the raiders");
+        arc.add_step("Learn their leader's name");
+
+        assert_eq!(
+            arc.complete_climax(ClimaxOutcome::Success),
+            Err(ArcError::StepsIncomplete)
+        );
+        assert_eq!(arc.resolve(), Err(ArcError::ClimaxNotReached));
+
+        assert_eq!(arc.complete_step(0), Ok(2));
+        assert_eq!(arc.complete_step(0), Err(ArcError::StepAlreadyCompleted(0)));
+        assert_eq!(arc.progress(), (1, 2));
+        assert_eq!(arc.complete_step(1), Ok(2));
+
+        assert_eq!(arc.complete_climax(ClimaxOutcome::Failure), Ok(2));
+        assert_eq!(arc.resolve(), Ok(1));
+        assert_eq!(arc.resolve(), Err(ArcError::AlreadyResolved));
+    }
+
+    #[test]
+    fn missing_step_is_an_error() {
+        let mut arc = CharacterArc::open(ArcTemplate::Custom("Tame".to_string()), "Tame the beast");
+        assert_eq!(arc.complete_step(3), Err(ArcError::NoSuchStep(3)));
+        assert_eq!(arc.template.to_string(), "Tame");
+    }
+}
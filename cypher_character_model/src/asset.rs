@@ -0,0 +1,122 @@
+//! Assets that ease tasks, such as tools, help, or favourable circumstances.
+
+use crate::condition::{EffectDuration, TimeEvent};
+
+/// The most assets that can ease any one task.
+pub const MAX_ASSETS_PER_TASK: u8 = 2;
+
+/// Something that eases tasks of a particular kind by one step.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Asset {
+    /// Where the asset comes from, such as "crowbar" or "ally's help".
+    pub source: String,
+    /// The kind of task the asset applies to.
+    pub task: String,
+    pub duration: EffectDuration,
+}
+
+impl Asset {
+    pub fn new(
+        source: impl Into<String>,
+        task: impl Into<String>,
+        duration: EffectDuration,
+    ) -> Self {
+        Self {
+            source: source.into(),
+            task: task.into(),
+            duration,
+        }
+    }
+
+    fn applies_to(&self, task: &str) -> bool {
+        self.task == task
+    }
+}
+
+/// The assets a character currently has.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Assets {
+    assets: Vec<Asset>,
+}
+
+impl Assets {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, asset: Asset) {
+        self.assets.push(asset);
+    }
+
+    /// Removes every asset from the given source.
+    pub fn remove(&mut self, source: &str) {
+        self.assets.retain(|asset| asset.source != source);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Asset> {
+        self.assets.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.assets.is_empty()
+    }
+
+    /// Advances all assets past the given event, dropping any that expire.
+    pub fn expire(&mut self, event: TimeEvent) {
+        self.assets
+            .retain_mut(|asset| asset.duration.advance(event));
+    }
+
+    /// The number of steps a task of the given kind is eased by, counting
+    /// both the character's assets and any that apply only to this attempt.
+    /// At most [`MAX_ASSETS_PER_TASK`] assets count towards a single task.
+    pub fn easing_for(&self, task: &str, attempt: &[Asset]) -> u8 {
+        let count = self
+            .assets
+            .iter()
+            .chain(attempt)
+            .filter(|asset| asset.applies_to(task))
+            .count();
+        count.min(MAX_ASSETS_PER_TASK as usize) as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn easing_is_capped_at_two_assets() {
+        let mut assets = Assets::new();
+        assets.add(Asset::new(
+            "climbing gear",
+            "climbing",
+            EffectDuration::UntilRemoved,
+        ));
+        assert_eq!(assets.easing_for("climbing", &[]), 1);
+        assert_eq!(assets.easing_for("swimming", &[]), 0);
+
+        let attempt = [
+            Asset::new("rope", "climbing", EffectDuration::ONE_ROUND),
+            Asset::new("ally's help", "climbing", EffectDuration::ONE_ROUND),
+        ];
+        assert_eq!(assets.easing_for("climbing", &attempt), 2);
+    }
+
+    #[test]
+    fn assets_expire() {
+        let mut assets = Assets::new();
+        assets.add(Asset::new("stim", "running", EffectDuration::ONE_ROUND));
+        assets.add(Asset::new(
+            "crowbar",
+            "prying",
+            EffectDuration::UntilRemoved,
+        ));
+
+        assets.expire(TimeEvent::RoundEnded);
+        assert_eq!(assets.easing_for("running", &[]), 0);
+
+        assets.remove("crowbar");
+        assert!(assets.is_empty());
+    }
+}
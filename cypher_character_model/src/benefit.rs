@@ -0,0 +1,168 @@
+//! XP spent on benefits other than character advancement.
+
+use crate::ledger::{InsufficientBalanceError, Ledger, Resource};
+
+/// The ledger account that XP spent on benefits is transferred to.
+pub const BENEFITS_ACCOUNT: &str = "XP benefits";
+
+/// How long a benefit bought with XP lasts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BenefitTerm {
+    /// A one-off benefit, such as a reroll.
+    Immediate,
+    /// A benefit lasting for an adventure or so, such as a temporary skill.
+    ShortTerm,
+    /// A lasting change to the character's situation, such as a familiar,
+    /// a home, a title or an artifact.
+    LongTerm,
+}
+
+impl BenefitTerm {
+    /// The standard XP cost for a benefit of this term.
+    pub fn cost(self) -> u64 {
+        match self {
+            BenefitTerm::Immediate => 1,
+            BenefitTerm::ShortTerm => 2,
+            BenefitTerm::LongTerm => 3,
+        }
+    }
+}
+
+/// A benefit a character spent XP on.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct XpBenefit {
+    pub term: BenefitTerm,
+    pub description: String,
+    pub cost: u64,
+    /// The session the XP was spent in, if recorded.
+    pub session: Option<String>,
+}
+
+impl XpBenefit {
+    /// A benefit at the standard cost for its term.
+    pub fn new(term: BenefitTerm, description: impl Into<String>) -> Self {
+        Self {
+            term,
+            description: description.into(),
+            cost: term.cost(),
+            session: None,
+        }
+    }
+
+    pub fn with_cost(mut self, cost: u64) -> Self {
+        self.cost = cost;
+        self
+    }
+
+    pub fn in_session(mut self, session: impl Into<String>) -> Self {
+        self.session = Some(session.into());
+        self
+    }
+}
+
+/// The benefits a character has bought with XP.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BenefitLog {
+    benefits: Vec<XpBenefit>,
+}
+
+impl BenefitLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Spends the given account's XP on a benefit, recording the transfer
+    /// in the ledger and the benefit in this log.
+    pub fn purchase(
+        &mut self,
+        benefit: XpBenefit,
+        ledger: &mut Ledger,
+        account: &str,
+    ) -> Result<(), InsufficientBalanceError> {
+        ledger.transfer(
+            account,
+            BENEFITS_ACCOUNT,
+            Resource::Xp,
+            benefit.cost,
+            benefit.description.clone(),
+        )?;
+        self.benefits.push(benefit);
+        Ok(())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &XpBenefit> {
+        self.benefits.iter()
+    }
+
+    /// The benefits of the given term.
+    pub fn by_term(&self, term: BenefitTerm) -> impl Iterator<Item = &XpBenefit> {
+        self.benefits
+            .iter()
+            .filter(move |benefit| benefit.term == term)
+    }
+
+    /// The total XP spent on benefits, saturating at `u64::MAX`.
+    pub fn total_spent(&self) -> u64 {
+        self.benefits
+            .iter()
+            .map(|benefit| benefit.cost)
+            .fold(0, u64::saturating_add)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn purchases_are_recorded_in_the_ledger() {
+        let mut ledger = Ledger::new();
+        ledger.grant("GM", "Kira", Resource::Xp, 5, "session rewards");
+
+        let mut benefits = BenefitLog::new();
+        benefits
+            .purchase(
+                XpBenefit::new(BenefitTerm::LongTerm, "A loyal familiar").in_session("Session 4"),
+                &mut ledger,
+                "Kira",
+            )
+            .unwrap();
+        benefits
+            .purchase(
+                XpBenefit::new(BenefitTerm::Immediate, "Reroll"),
+                &mut ledger,
+                "Kira",
+            )
+            .unwrap();
+
+        assert!(benefits
+            .purchase(
+                XpBenefit::new(BenefitTerm::ShortTerm, "Contact"),
+                &mut ledger,
+                "Kira"
+            )
+            .is_err());
+        assert_eq!(benefits.total_spent(), 4);
+        assert_eq!(benefits.by_term(BenefitTerm::LongTerm).count(), 1);
+        assert_eq!(ledger.balance("Kira", &Resource::Xp), 1);
+    }
+
+    #[test]
+    fn total_spent_saturates() {
+        let mut ledger = Ledger::new();
+        ledger.grant("GM", "Kira", Resource::Xp, u64::MAX, "windfall");
+        ledger.grant("GM", "Kira", Resource::Xp, u64::MAX, "windfall");
+
+        let mut benefits = BenefitLog::new();
+        for _ in 0..2 {
+            benefits
+                .purchase(
+                    XpBenefit::new(BenefitTerm::LongTerm, "A castle").with_cost(u64::MAX),
+                    &mut ledger,
+                    "Kira",
+                )
+                .unwrap();
+        }
+        assert_eq!(benefits.total_spent(), u64::MAX);
+    }
+}
@@ -0,0 +1,174 @@
+//! Communities that characters belong to and build up, for domain-level play.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::id::CommunityId;
+
+/// How badly a community has been harmed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CommunityState {
+    #[default]
+    Hale,
+    Impaired,
+    Debilitated,
+    Destroyed,
+}
+
+impl CommunityState {
+    fn worse(self) -> Self {
+        match self {
+            CommunityState::Hale => CommunityState::Impaired,
+            CommunityState::Impaired => CommunityState::Debilitated,
+            CommunityState::Debilitated | CommunityState::Destroyed => CommunityState::Destroyed,
+        }
+    }
+
+    fn better(self) -> Self {
+        match self {
+            CommunityState::Hale | CommunityState::Impaired => CommunityState::Hale,
+            CommunityState::Debilitated => CommunityState::Impaired,
+            CommunityState::Destroyed => CommunityState::Destroyed,
+        }
+    }
+}
+
+/// A settlement or group, with its own rank and health.
+///
+/// A community's health is three times its rank. When damage takes its
+/// health to zero, the community moves one step down the damage track and
+/// its health resets, with any leftover damage carried over.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Community {
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub id: Option<CommunityId>,
+    pub name: String,
+    pub rank: u8,
+    pub health: u16,
+    /// The community's buildings and systems, such as walls or a forge.
+    pub infrastructure: Vec<String>,
+    pub state: CommunityState,
+}
+
+impl Community {
+    pub fn new(name: impl Into<String>, rank: u8) -> Self {
+        Self {
+            id: None,
+            name: name.into(),
+            rank,
+            health: Self::health_for(rank),
+            infrastructure: Vec::new(),
+            state: CommunityState::Hale,
+        }
+    }
+
+    pub fn with_id(mut self, id: CommunityId) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    fn health_for(rank: u8) -> u16 {
+        u16::from(rank) * 3
+    }
+
+    pub fn max_health(&self) -> u16 {
+        Self::health_for(self.rank)
+    }
+
+    /// Deals damage to the community, moving it down the damage track each
+    /// time its health runs out.
+    pub fn take_damage(&mut self, mut damage: u16) {
+        while damage > 0 && self.state != CommunityState::Destroyed {
+            if damage < self.health {
+                self.health -= damage;
+                return;
+            }
+            damage -= self.health;
+            self.state = self.state.worse();
+            self.health = if self.state == CommunityState::Destroyed {
+                0
+            } else {
+                self.max_health()
+            };
+        }
+    }
+
+    /// Restores health, as when characters help rebuild. Health cannot go
+    /// above the community's maximum.
+    pub fn repair(&mut self, amount: u16) {
+        if self.state != CommunityState::Destroyed {
+            self.health = self.health.saturating_add(amount).min(self.max_health());
+        }
+    }
+
+    /// Moves the community one step up the damage track.
+    pub fn recover(&mut self) {
+        self.state = self.state.better();
+    }
+
+    /// Raises the community's rank, restoring it to full health at the new
+    /// rank. A destroyed community cannot grow.
+    pub fn increase_rank(&mut self) {
+        if self.state != CommunityState::Destroyed {
+            self.rank = self.rank.saturating_add(1);
+            self.health = self.max_health();
+        }
+    }
+
+    pub fn add_infrastructure(&mut self, infrastructure: impl Into<String>) {
+        self.infrastructure.push(infrastructure.into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn damage_moves_down_the_track() {
+        let mut community = Community::new("Hollowmere", 3);
+        assert_eq!(community.health, 9);
+
+        community.take_damage(4);
+        assert_eq!(community.health, 5);
+
+        community.take_damage(7);
+        assert_eq!(community.state, CommunityState::Impaired);
+        assert_eq!(community.health, 7);
+
+        community.take_damage(100);
+        assert_eq!(community.state, CommunityState::Destroyed);
+        assert_eq!(community.health, 0);
+    }
+
+    #[test]
+    fn characters_can_aid_the_community() {
+        let mut community = Community::new("Hollowmere", 2);
+        community.take_damage(6);
+        assert_eq!(community.state, CommunityState::Impaired);
+
+        community.take_damage(4);
+        community.repair(10);
+        assert_eq!(community.health, 6);
+        community.recover();
+        assert_eq!(community.state, CommunityState::Hale);
+
+        community.increase_rank();
+        community.add_infrastructure("Palisade");
+        assert_eq!(community.health, 9);
+        assert_eq!(community.infrastructure, ["Palisade"]);
+    }
+
+    #[test]
+    fn destroyed_communities_cannot_grow() {
+        let mut community = Community::new("Hollow", 1);
+        community.take_damage(100);
+        assert_eq!(community.state, CommunityState::Destroyed);
+
+        community.increase_rank();
+        assert_eq!(community.rank, 1);
+        assert_eq!(community.health, 0);
+    }
+}
@@ -0,0 +1,255 @@
+//! Conditions and other temporary effects that can be applied to a character.
+
+/// How long an [`Effect`] lasts before it expires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EffectDuration {
+    /// Lasts for the given number of rounds.
+    Rounds(u8),
+    /// Lasts for the given number of hours.
+    Hours(u8),
+    /// Lasts until the character next makes a recovery roll.
+    UntilRecoveryRoll,
+    /// Lasts until explicitly removed.
+    UntilRemoved,
+}
+
+impl EffectDuration {
+    /// An effect that lasts for a single round.
+    pub const ONE_ROUND: Self = Self::Rounds(1);
+    /// An effect that lasts for a single hour.
+    pub const ONE_HOUR: Self = Self::Hours(1);
+
+    /// Advances this duration past the given event, returning `false` once
+    /// it has run out.
+    pub(crate) fn advance(&mut self, event: TimeEvent) -> bool {
+        match (self, event) {
+            (Self::Rounds(n), TimeEvent::RoundEnded) | (Self::Hours(n), TimeEvent::HourPassed) => {
+                *n = n.saturating_sub(1);
+                *n > 0
+            }
+            (Self::UntilRecoveryRoll, TimeEvent::RecoveryRoll) => false,
+            _ => true,
+        }
+    }
+}
+
+/// Something that passes in the fiction and may cause effects to expire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeEvent {
+    RoundEnded,
+    HourPassed,
+    RecoveryRoll,
+}
+
+/// A condition affecting a character.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Condition {
+    /// The difficulty of every task is increased by one step.
+    Dazed,
+    /// The character loses their turn and cannot take actions.
+    Stunned,
+    /// The character takes the given amount of damage at the end of each round.
+    Poisoned { damage: u8 },
+    /// Tasks of the named kind are hindered by the given number of steps.
+    Hindered { task: String, steps: u8 },
+    /// Tasks of the named kind are eased by the given number of steps.
+    Eased { task: String, steps: u8 },
+    /// The character gains the given amount of Armor.
+    BonusArmor(u8),
+}
+
+impl Condition {
+    /// The net change in difficulty steps this condition applies to a task
+    /// of the given kind. Positive values hinder the task, negative values
+    /// ease it.
+    pub fn difficulty_modifier(&self, task: &str) -> i8 {
+        match self {
+            Condition::Dazed => 1,
+            Condition::Hindered { task: kind, steps } if kind == task => {
+                i8::try_from(*steps).unwrap_or(i8::MAX)
+            }
+            Condition::Eased { task: kind, steps } if kind == task => {
+                -i8::try_from(*steps).unwrap_or(i8::MAX)
+            }
+            _ => 0,
+        }
+    }
+}
+
+/// A [`Condition`] together with how long it lasts.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Effect {
+    pub condition: Condition,
+    pub duration: EffectDuration,
+}
+
+impl Effect {
+    pub fn new(condition: Condition, duration: EffectDuration) -> Self {
+        Self {
+            condition,
+            duration,
+        }
+    }
+}
+
+/// The set of effects currently active on a character.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ActiveEffects {
+    effects: Vec<Effect>,
+}
+
+impl ActiveEffects {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, effect: Effect) {
+        self.effects.push(effect);
+    }
+
+    /// Removes every effect with the given condition.
+    pub fn remove(&mut self, condition: &Condition) {
+        self.effects.retain(|effect| &effect.condition != condition);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Effect> {
+        self.effects.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.effects.is_empty()
+    }
+
+    /// Advances all effects past the given event, dropping any that expire.
+    pub fn expire(&mut self, event: TimeEvent) {
+        self.effects
+            .retain_mut(|effect| effect.duration.advance(event));
+    }
+
+    /// The net change in difficulty steps for a task of the given kind.
+    /// Positive values hinder the task, negative values ease it.
+    pub fn difficulty_modifier(&self, task: &str) -> i8 {
+        self.effects
+            .iter()
+            .map(|effect| effect.condition.difficulty_modifier(task))
+            .fold(0, i8::saturating_add)
+    }
+
+    /// The total Armor granted by active effects.
+    pub fn bonus_armor(&self) -> u8 {
+        self.effects
+            .iter()
+            .map(|effect| match effect.condition {
+                Condition::BonusArmor(armor) => armor,
+                _ => 0,
+            })
+            .fold(0, u8::saturating_add)
+    }
+
+    /// The damage taken at the end of each round from active effects.
+    pub fn damage_per_round(&self) -> u8 {
+        self.effects
+            .iter()
+            .map(|effect| match effect.condition {
+                Condition::Poisoned { damage } => damage,
+                _ => 0,
+            })
+            .fold(0, u8::saturating_add)
+    }
+
+    /// Whether the character is able to take actions this round.
+    pub fn can_act(&self) -> bool {
+        !self
+            .effects
+            .iter()
+            .any(|effect| effect.condition == Condition::Stunned)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn difficulty_modifier_combines_conditions() {
+        let mut effects = ActiveEffects::new();
+        effects.add(Effect::new(Condition::Dazed, EffectDuration::ONE_ROUND));
+        effects.add(Effect::new(
+            Condition::Hindered {
+                task: "climbing".to_string(),
+                steps: 2,
+            },
+            EffectDuration::UntilRemoved,
+        ));
+        effects.add(Effect::new(
+            Condition::Eased {
+                task: "stealth".to_string(),
+                steps: 1,
+            },
+            EffectDuration::UntilRemoved,
+        ));
+
+        assert_eq!(effects.difficulty_modifier("climbing"), 3);
+        assert_eq!(effects.difficulty_modifier("stealth"), 0);
+        assert_eq!(effects.difficulty_modifier("lore"), 1);
+    }
+
+    #[test]
+    fn effects_expire_on_matching_events() {
+        let mut effects = ActiveEffects::new();
+        effects.add(Effect::new(Condition::Stunned, EffectDuration::ONE_ROUND));
+        effects.add(Effect::new(
+            Condition::BonusArmor(1),
+            EffectDuration::ONE_HOUR,
+        ));
+        effects.add(Effect::new(
+            Condition::Poisoned { damage: 2 },
+            EffectDuration::UntilRecoveryRoll,
+        ));
+        assert!(!effects.can_act());
+
+        effects.expire(TimeEvent::RoundEnded);
+        assert!(effects.can_act());
+        assert_eq!(effects.bonus_armor(), 1);
+        assert_eq!(effects.damage_per_round(), 2);
+
+        effects.expire(TimeEvent::HourPassed);
+        assert_eq!(effects.bonus_armor(), 0);
+
+        effects.expire(TimeEvent::RecoveryRoll);
+        assert!(effects.is_empty());
+    }
+
+    #[test]
+    fn large_values_saturate() {
+        let mut effects = ActiveEffects::new();
+        effects.add(Effect::new(
+            Condition::Hindered {
+                task: "climbing".to_string(),
+                steps: 200,
+            },
+            EffectDuration::UntilRemoved,
+        ));
+        effects.add(Effect::new(Condition::Dazed, EffectDuration::ONE_ROUND));
+        effects.add(Effect::new(
+            Condition::BonusArmor(200),
+            EffectDuration::UntilRemoved,
+        ));
+        effects.add(Effect::new(
+            Condition::BonusArmor(100),
+            EffectDuration::UntilRemoved,
+        ));
+        effects.add(Effect::new(
+            Condition::Poisoned { damage: 200 },
+            EffectDuration::UntilRecoveryRoll,
+        ));
+        effects.add(Effect::new(
+            Condition::Poisoned { damage: 100 },
+            EffectDuration::UntilRecoveryRoll,
+        ));
+
+        assert_eq!(effects.difficulty_modifier("climbing"), i8::MAX);
+        assert_eq!(effects.bonus_armor(), u8::MAX);
+        assert_eq!(effects.damage_per_round(), u8::MAX);
+    }
+}
@@ -0,0 +1,169 @@
+//! Crafting and repairing items over one or more sessions.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::id::CraftingProjectId;
+use crate::skill::Training;
+
+/// A material an item needs, and how much of it has been gathered.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Material {
+    pub name: String,
+    pub required: u32,
+    pub gathered: u32,
+}
+
+impl Material {
+    pub fn is_gathered(&self) -> bool {
+        self.gathered >= self.required
+    }
+}
+
+/// An item a character is working on.
+///
+/// Crafting an item is a task with a difficulty equal to the item's level,
+/// adjusted by the crafter's training. An assessment task of the same
+/// difficulty tells the crafter what the item needs. Until it succeeds, no
+/// work or materials can be recorded against the project.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CraftingProject {
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub id: Option<CraftingProjectId>,
+    pub item: String,
+    pub level: u8,
+    /// The hours of work the item takes.
+    #[cfg_attr(feature = "serde", serde(alias = "requiredHours"))]
+    pub required_hours: u32,
+    #[cfg_attr(feature = "serde", serde(alias = "hoursWorked"))]
+    pub hours_worked: u32,
+    pub materials: Vec<Material>,
+    /// Whether an assessment task has succeeded for this item.
+    pub assessed: bool,
+}
+
+impl CraftingProject {
+    pub fn new(item: impl Into<String>, level: u8, required_hours: u32) -> Self {
+        Self {
+            id: None,
+            item: item.into(),
+            level,
+            required_hours,
+            hours_worked: 0,
+            materials: Vec::new(),
+            assessed: false,
+        }
+    }
+
+    pub fn with_id(mut self, id: CraftingProjectId) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn requiring(mut self, material: impl Into<String>, amount: u32) -> Self {
+        self.materials.push(Material {
+            name: material.into(),
+            required: amount,
+            gathered: 0,
+        });
+        self
+    }
+
+    /// The difficulty of crafting or assessing the item for a crafter with
+    /// the given training.
+    pub fn difficulty(&self, training: Training) -> u8 {
+        training.adjust(self.level)
+    }
+
+    /// Records a successful assessment task, after which work and
+    /// materials can be recorded.
+    pub fn assess(&mut self) {
+        self.assessed = true;
+    }
+
+    /// Adds gathered materials. Materials the item does not need, or any
+    /// gathered before the item is assessed, are ignored; returns whether
+    /// the material was recorded.
+    pub fn gather(&mut self, material: &str, amount: u32) -> bool {
+        if !self.assessed {
+            return false;
+        }
+        match self
+            .materials
+            .iter_mut()
+            .find(|needed| needed.name == material)
+        {
+            Some(needed) => {
+                needed.gathered = needed.gathered.saturating_add(amount);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Records hours spent working on the item. Returns whether they were
+    /// recorded, which they are not until the item has been assessed.
+    pub fn work(&mut self, hours: u32) -> bool {
+        if !self.assessed {
+            return false;
+        }
+        self.hours_worked = self
+            .hours_worked
+            .saturating_add(hours)
+            .min(self.required_hours);
+        true
+    }
+
+    /// The hours of work still needed.
+    pub fn hours_remaining(&self) -> u32 {
+        self.required_hours.saturating_sub(self.hours_worked)
+    }
+
+    /// Whether the item has been assessed and has all its materials and work
+    /// done, and is ready for the final crafting task.
+    pub fn is_ready(&self) -> bool {
+        self.assessed
+            && self.hours_remaining() == 0
+            && self.materials.iter().all(Material::is_gathered)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn project_tracks_progress_across_sessions() {
+        let mut project = CraftingProject::new("Longbow", 3, 10)
+            .requiring("yew stave", 1)
+            .requiring("sinew", 2);
+        assert_eq!(project.difficulty(Training::Trained), 2);
+
+        assert!(!project.work(6));
+        assert!(!project.gather("yew stave", 1));
+        assert_eq!(project.hours_remaining(), 10);
+
+        project.assess();
+        assert!(project.work(6));
+        assert!(project.gather("yew stave", 1));
+        assert!(!project.gather("iron", 4));
+        assert!(!project.is_ready());
+
+        project.work(6);
+        project.gather("sinew", 2);
+        assert_eq!(project.hours_remaining(), 0);
+        assert!(project.is_ready());
+    }
+
+    #[test]
+    fn shortening_a_project_after_work_leaves_nothing_remaining() {
+        let mut project = CraftingProject::new("Lockpick", 2, 8);
+        project.assess();
+        project.work(6);
+        project.required_hours = 4;
+        assert_eq!(project.hours_remaining(), 0);
+        assert!(project.is_ready());
+    }
+}
@@ -0,0 +1,244 @@
+//! Cyphers, the tables used to hand them out at random, and the cyphers a
+//! character carries.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::id::CypherId;
+
+/// How a cypher appears in the setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CypherForm {
+    /// Hidden within the character, such as a power or a blessing.
+    Subtle,
+    /// A physical object, such as a pill, device or potion.
+    Manifest,
+}
+
+/// A single-use item or power.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Cypher {
+    /// Assigned when the cypher is rolled. Cyphers saved before IDs were
+    /// added have none.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub id: Option<CypherId>,
+    pub name: String,
+    pub level: u8,
+    pub form: CypherForm,
+}
+
+impl Cypher {
+    /// Rolls a cypher from the core table.
+    pub fn random(rng: &mut impl Rng) -> Self {
+        CypherTable::core()
+            .roll(rng)
+            .expect("the core cypher table is not empty")
+    }
+}
+
+/// An entry in a [`CypherTable`], whose level is 1d6 plus `level_bonus`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CypherTableEntry {
+    pub name: String,
+    pub level_bonus: u8,
+}
+
+impl CypherTableEntry {
+    pub fn new(name: impl Into<String>, level_bonus: u8) -> Self {
+        Self {
+            name: name.into(),
+            level_bonus,
+        }
+    }
+
+    /// Rolls the level and form of a cypher for this entry.
+    pub fn roll(&self, rng: &mut impl Rng) -> Cypher {
+        let form = if rng.gen_bool(0.5) {
+            CypherForm::Subtle
+        } else {
+            CypherForm::Manifest
+        };
+        Cypher {
+            id: Some(CypherId::random(rng)),
+            name: self.name.clone(),
+            level: rng.gen_range(1..=6u8).saturating_add(self.level_bonus),
+            form,
+        }
+    }
+}
+
+const CORE_TABLE: &[(&str, u8)] = &[
+    ("Adhesion", 0),
+    ("Antivenom", 2),
+    ("Banishing", 2),
+    ("Density", 0),
+    ("Detonation", 2),
+    ("Detonation (flash)", 2),
+    ("Detonation (web)", 2),
+    ("Disarm", 0),
+    ("Fireproofing", 0),
+    ("Healing", 2),
+    ("Heat Attack", 2),
+    ("Intellect Boost", 2),
+    ("Invisibility", 2),
+    ("Mind Reading", 0),
+    ("Rejuvenator", 2),
+    ("Sheen", 0),
+    ("Speed Boost", 2),
+    ("Stim", 0),
+    ("Strength Boost", 2),
+    ("Teleporter", 2),
+];
+
+/// A table of cyphers to roll on.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CypherTable {
+    entries: Vec<CypherTableEntry>,
+}
+
+impl CypherTable {
+    pub fn new(entries: Vec<CypherTableEntry>) -> Self {
+        Self { entries }
+    }
+
+    /// The built-in table of core cyphers.
+    pub fn core() -> Self {
+        Self::new(
+            CORE_TABLE
+                .iter()
+                .map(|&(name, level_bonus)| CypherTableEntry::new(name, level_bonus))
+                .collect(),
+        )
+    }
+
+    pub fn entries(&self) -> &[CypherTableEntry] {
+        &self.entries
+    }
+
+    /// Rolls a cypher from this table, or `None` if the table is empty.
+    pub fn roll(&self, rng: &mut impl Rng) -> Option<Cypher> {
+        self.entries.choose(rng).map(|entry| entry.roll(rng))
+    }
+}
+
+/// The cyphers a character is carrying.
+///
+/// A character can carry more cyphers than their limit, but the extra
+/// cyphers interfere with each other and draw escalating GM intrusions.
+/// GM tools can poll [`CarriedCyphers::cypher_danger_level`] to see how
+/// far over the limit the character is.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CarriedCyphers {
+    limit: u8,
+    cyphers: Vec<Cypher>,
+}
+
+impl CarriedCyphers {
+    pub fn new(limit: u8) -> Self {
+        Self {
+            limit,
+            cyphers: Vec::new(),
+        }
+    }
+
+    pub fn limit(&self) -> u8 {
+        self.limit
+    }
+
+    /// Changes the limit, as when the character gains a tier.
+    pub fn set_limit(&mut self, limit: u8) {
+        self.limit = limit;
+    }
+
+    /// Adds a cypher, even if it takes the character over their limit.
+    pub fn add(&mut self, cypher: Cypher) {
+        self.cyphers.push(cypher);
+    }
+
+    /// Removes the cypher at the given index, as when it is used or
+    /// discarded.
+    pub fn remove(&mut self, index: usize) -> Option<Cypher> {
+        (index < self.cyphers.len()).then(|| self.cyphers.remove(index))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Cypher> {
+        self.cyphers.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cyphers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cyphers.is_empty()
+    }
+
+    pub fn is_over_limit(&self) -> bool {
+        self.cypher_danger_level() > 0
+    }
+
+    /// How many cyphers the character carries beyond their limit. Zero
+    /// means they are safe; each extra cypher makes the consequences worse.
+    pub fn cypher_danger_level(&self) -> usize {
+        self.cyphers.len().saturating_sub(usize::from(self.limit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn random_cyphers_come_from_core_table() {
+        let table = CypherTable::core();
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..100 {
+            let cypher = Cypher::random(&mut rng);
+            let entry = table
+                .entries()
+                .iter()
+                .find(|entry| entry.name == cypher.name)
+                .unwrap();
+            assert!((1 + entry.level_bonus..=6 + entry.level_bonus).contains(&cypher.level));
+            assert!(cypher.id.is_some());
+        }
+    }
+
+    #[test]
+    fn empty_table_rolls_nothing() {
+        let mut rng = StdRng::seed_from_u64(0);
+        assert_eq!(CypherTable::new(Vec::new()).roll(&mut rng), None);
+    }
+
+    #[test]
+    fn large_level_bonus_saturates() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let cypher = CypherTableEntry::new("Overcharged", u8::MAX).roll(&mut rng);
+        assert_eq!(cypher.level, u8::MAX);
+    }
+
+    #[test]
+    fn carrying_too_many_cyphers_raises_the_danger_level() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut carried = CarriedCyphers::new(2);
+        carried.add(Cypher::random(&mut rng));
+        carried.add(Cypher::random(&mut rng));
+        assert_eq!(carried.cypher_danger_level(), 0);
+
+        carried.add(Cypher::random(&mut rng));
+        carried.add(Cypher::random(&mut rng));
+        assert_eq!(carried.len(), 4);
+        assert_eq!(carried.cypher_danger_level(), 2);
+
+        carried.set_limit(3);
+        assert!(carried.remove(0).is_some());
+        assert!(carried.remove(10).is_none());
+        assert!(!carried.is_over_limit());
+    }
+}
@@ -0,0 +1,51 @@
+//! Damage, and the scales it is measured in.
+
+/// The number of points of personal-scale damage that one point of
+/// vehicle-scale damage is worth.
+pub const VEHICLE_SCALE: u8 = 10;
+
+/// The scale an amount of damage is measured in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DamageScale {
+    /// Damage dealt by and to characters and creatures.
+    Personal,
+    /// Damage dealt by and to vehicles.
+    Vehicle,
+}
+
+impl DamageScale {
+    /// Converts an amount of damage in this scale to the given scale.
+    /// Converting down to vehicle scale rounds down, so personal weapons
+    /// dealing less than [`VEHICLE_SCALE`] cannot harm a vehicle.
+    pub fn convert(self, damage: u8, to: DamageScale) -> u8 {
+        match (self, to) {
+            (DamageScale::Personal, DamageScale::Vehicle) => damage / VEHICLE_SCALE,
+            (DamageScale::Vehicle, DamageScale::Personal) => damage.saturating_mul(VEHICLE_SCALE),
+            _ => damage,
+        }
+    }
+}
+
+/// Reduces `damage` by `armor` and takes it from `health`, which does not
+/// go below zero. Returns the damage actually taken.
+pub(crate) fn apply(health: &mut u8, armor: u8, damage: u8) -> u8 {
+    let taken = damage.saturating_sub(armor).min(*health);
+    *health -= taken;
+    taken
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_between_scales() {
+        use DamageScale::{Personal, Vehicle};
+
+        assert_eq!(Vehicle.convert(3, Personal), 30);
+        assert_eq!(Vehicle.convert(30, Personal), u8::MAX);
+        assert_eq!(Personal.convert(25, Vehicle), 2);
+        assert_eq!(Personal.convert(9, Vehicle), 0);
+        assert_eq!(Personal.convert(9, Personal), 9);
+    }
+}
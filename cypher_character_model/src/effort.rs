@@ -0,0 +1,33 @@
+//! The three stat Pools that Effort can be applied from.
+
+use std::fmt;
+
+/// One of a character's three stats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EffortType {
+    Might,
+    Speed,
+    Intellect,
+}
+
+impl EffortType {
+    /// Every stat, in the order they appear on the character sheet.
+    pub const ALL: [EffortType; 3] = [EffortType::Might, EffortType::Speed, EffortType::Intellect];
+
+    /// Iterates over every stat, in the order they appear on the character
+    /// sheet.
+    pub fn iter() -> impl Iterator<Item = EffortType> {
+        Self::ALL.into_iter()
+    }
+}
+
+impl fmt::Display for EffortType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            EffortType::Might => "Might",
+            EffortType::Speed => "Speed",
+            EffortType::Intellect => "Intellect",
+        };
+        f.write_str(name)
+    }
+}
@@ -0,0 +1,212 @@
+//! Combat encounters: initiative order and rounds.
+
+use rand::Rng;
+
+use crate::condition::{ActiveEffects, TimeEvent};
+use crate::npc::Npc;
+
+/// The task name NPC [`Modification`](crate::npc::Modification)s use to
+/// change the level they act at for initiative.
+pub const INITIATIVE_TASK: &str = "initiative";
+
+/// A player character taking part in an encounter.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EncounterPc {
+    pub name: String,
+    /// The result of the character's Speed-based initiative roll.
+    pub initiative: u8,
+    pub effects: ActiveEffects,
+}
+
+/// An NPC taking part in an encounter.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EncounterNpc {
+    pub npc: Npc,
+    pub effects: ActiveEffects,
+}
+
+/// Whose turn it is within a round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Turn {
+    /// The player character at the given index.
+    Pc(usize),
+    /// All of the NPCs, who act together.
+    Npcs,
+}
+
+/// A combat encounter between player characters and NPCs.
+///
+/// Each character's initiative roll is a Speed task against the level of
+/// the highest-level NPC, counting any modification to its initiative. Characters who succeed act before the NPCs and
+/// those who fail act after them. Adding a participant recalculates the
+/// order, so everyone should be added before the first turn is taken.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Encounter {
+    pcs: Vec<EncounterPc>,
+    npcs: Vec<EncounterNpc>,
+    order: Vec<Turn>,
+    turn: usize,
+    round: u32,
+}
+
+impl Encounter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_npc(&mut self, npc: Npc) {
+        self.npcs.push(EncounterNpc {
+            npc,
+            effects: ActiveEffects::new(),
+        });
+        self.order_turns();
+    }
+
+    /// Adds a player character with the result of their initiative roll.
+    pub fn add_pc(&mut self, name: impl Into<String>, initiative: u8) {
+        self.pcs.push(EncounterPc {
+            name: name.into(),
+            initiative,
+            effects: ActiveEffects::new(),
+        });
+        self.order_turns();
+    }
+
+    /// Adds a player character, rolling a d20 for their initiative.
+    pub fn roll_pc(&mut self, name: impl Into<String>, rng: &mut impl Rng) -> u8 {
+        let initiative = rng.gen_range(1..=20);
+        self.add_pc(name, initiative);
+        initiative
+    }
+
+    /// The number a character's initiative roll must reach to act before
+    /// the NPCs.
+    pub fn target_number(&self) -> u8 {
+        self.npcs
+            .iter()
+            .map(|entry| entry.npc.target_number_for(INITIATIVE_TASK))
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn order_turns(&mut self) {
+        let target = self.target_number();
+        let mut pcs: Vec<usize> = (0..self.pcs.len()).collect();
+        pcs.sort_by_key(|&i| std::cmp::Reverse(self.pcs[i].initiative));
+        let (before, after): (Vec<usize>, Vec<usize>) = pcs
+            .into_iter()
+            .partition(|&i| self.pcs[i].initiative >= target);
+
+        self.order = before.into_iter().map(Turn::Pc).collect();
+        if !self.npcs.is_empty() {
+            self.order.push(Turn::Npcs);
+        }
+        self.order.extend(after.into_iter().map(Turn::Pc));
+        self.turn = 0;
+    }
+
+    pub fn pcs(&self) -> &[EncounterPc] {
+        &self.pcs
+    }
+
+    pub fn pcs_mut(&mut self) -> &mut [EncounterPc] {
+        &mut self.pcs
+    }
+
+    pub fn npcs(&self) -> &[EncounterNpc] {
+        &self.npcs
+    }
+
+    pub fn npcs_mut(&mut self) -> &mut [EncounterNpc] {
+        &mut self.npcs
+    }
+
+    /// The turns of each round, in order.
+    pub fn order(&self) -> &[Turn] {
+        &self.order
+    }
+
+    /// The current round, starting from 1.
+    pub fn round(&self) -> u32 {
+        self.round + 1
+    }
+
+    /// Whose turn it currently is, if anyone is taking part.
+    pub fn current(&self) -> Option<Turn> {
+        self.order.get(self.turn).copied()
+    }
+
+    /// Moves to the next turn. When the last turn of a round ends, a new
+    /// round begins and effects lasting a number of rounds tick down.
+    pub fn advance(&mut self) {
+        if self.order.is_empty() {
+            return;
+        }
+        self.turn += 1;
+        if self.turn == self.order.len() {
+            self.turn = 0;
+            self.round += 1;
+            for pc in &mut self.pcs {
+                pc.effects.expire(TimeEvent::RoundEnded);
+            }
+            for npc in &mut self.npcs {
+                npc.effects.expire(TimeEvent::RoundEnded);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::condition::{Condition, Effect, EffectDuration};
+    use crate::npc::Modification;
+
+    #[test]
+    fn orders_pcs_around_npcs() {
+        let mut encounter = Encounter::new();
+        encounter.add_npc(Npc::new("Bandit", 2));
+        encounter.add_npc(Npc::new("Bandit Chief", 4));
+        encounter.add_pc("Slow", 5);
+        encounter.add_pc("Fast", 17);
+        encounter.add_pc("Quick", 12);
+
+        assert_eq!(encounter.target_number(), 12);
+        assert_eq!(
+            encounter.order(),
+            [Turn::Pc(1), Turn::Pc(2), Turn::Npcs, Turn::Pc(0)]
+        );
+    }
+
+    #[test]
+    fn npcs_modified_for_initiative_raise_the_target() {
+        let mut encounter = Encounter::new();
+        encounter.add_npc(
+            Npc::new("Ambusher", 2).with_modification(Modification::new(INITIATIVE_TASK, 5)),
+        );
+        encounter.add_pc("Kira", 12);
+
+        assert_eq!(encounter.target_number(), 15);
+        assert_eq!(encounter.order(), [Turn::Npcs, Turn::Pc(0)]);
+    }
+
+    #[test]
+    fn advancing_past_the_last_turn_starts_a_new_round() {
+        let mut encounter = Encounter::new();
+        encounter.add_npc(Npc::new("Bandit", 2));
+        encounter.add_pc("Kira", 10);
+        encounter.pcs_mut()[0]
+            .effects
+            .add(Effect::new(Condition::Dazed, EffectDuration::ONE_ROUND));
+
+        assert_eq!(encounter.current(), Some(Turn::Pc(0)));
+        encounter.advance();
+        assert_eq!(encounter.current(), Some(Turn::Npcs));
+        assert_eq!(encounter.round(), 1);
+
+        encounter.advance();
+        assert_eq!(encounter.current(), Some(Turn::Pc(0)));
+        assert_eq!(encounter.round(), 2);
+        assert!(encounter.pcs()[0].effects.is_empty());
+    }
+}
@@ -0,0 +1,57 @@
+//! Followers and companions that accompany a character.
+
+use crate::npc::Npc;
+
+/// A follower or companion, such as one granted by a focus.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Follower {
+    /// The follower's stats.
+    pub npc: Npc,
+    /// The kinds of tasks the follower helps with, each of which they
+    /// provide an asset on.
+    pub assists: Vec<String>,
+}
+
+impl Follower {
+    pub fn new(npc: Npc) -> Self {
+        Self {
+            npc,
+            assists: Vec::new(),
+        }
+    }
+
+    pub fn assisting_with(mut self, task: impl Into<String>) -> Self {
+        self.assists.push(task.into());
+        self
+    }
+
+    /// The number of assets this follower provides on a task of the given
+    /// kind. A defeated follower provides none.
+    pub fn assets_for(&self, task: &str) -> u8 {
+        if !self.npc.is_defeated() && self.assists.iter().any(|kind| kind == task) {
+            1
+        } else {
+            0
+        }
+    }
+
+    /// Deals damage to this follower. Returns the damage actually taken.
+    pub fn take_damage(&mut self, damage: u8) -> u8 {
+        self.npc.take_damage(damage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn assists_until_defeated() {
+        let mut follower = Follower::new(Npc::new("Hound", 2)).assisting_with("tracking");
+        assert_eq!(follower.assets_for("tracking"), 1);
+        assert_eq!(follower.assets_for("climbing"), 0);
+
+        follower.take_damage(6);
+        assert_eq!(follower.assets_for("tracking"), 0);
+    }
+}
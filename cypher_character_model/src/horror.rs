@@ -0,0 +1,146 @@
+//! Horror mode, where the chance of a GM intrusion climbs as play goes on
+//! and characters are worn down by what they witness.
+
+/// Tracks the GM intrusion range for a scene played in horror mode.
+///
+/// The range starts at 1 and grows by one after every d20 roll that does
+/// not land in it. A roll in the range triggers a GM intrusion and resets
+/// the range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HorrorMode {
+    intrusion_range: u8,
+}
+
+impl Default for HorrorMode {
+    fn default() -> Self {
+        Self { intrusion_range: 1 }
+    }
+}
+
+impl HorrorMode {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The highest d20 roll that triggers a GM intrusion.
+    pub fn intrusion_range(&self) -> u8 {
+        self.intrusion_range
+    }
+
+    /// Records a d20 roll, returning whether it triggers a GM intrusion.
+    pub fn record_roll(&mut self, roll: u8) -> bool {
+        if roll <= self.intrusion_range {
+            self.reset();
+            true
+        } else {
+            self.intrusion_range = (self.intrusion_range + 1).min(20);
+            false
+        }
+    }
+
+    /// Resets the intrusion range, as at the end of a scene.
+    pub fn reset(&mut self) {
+        self.intrusion_range = 1;
+    }
+}
+
+/// How far a character has been pushed by the horrors they have faced.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MadnessState {
+    #[default]
+    Steady,
+    Shaken,
+    Traumatized,
+    /// The character is lost to madness and is no longer under the
+    /// player's control.
+    Mad,
+}
+
+impl MadnessState {
+    fn from_step(step: u8) -> Self {
+        match step {
+            0 => MadnessState::Steady,
+            1 => MadnessState::Shaken,
+            2 => MadnessState::Traumatized,
+            _ => MadnessState::Mad,
+        }
+    }
+}
+
+/// A character's trauma track for horror mode.
+///
+/// Horrific experiences add madness points. Each time the total reaches a
+/// multiple of `points_per_step`, the character moves one step down the
+/// track. Rest and therapy remove points, moving them back up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MadnessTrack {
+    points: u8,
+    points_per_step: u8,
+}
+
+impl MadnessTrack {
+    /// Creates an empty track. A `points_per_step` of zero is treated as one.
+    pub fn new(points_per_step: u8) -> Self {
+        Self {
+            points: 0,
+            points_per_step: points_per_step.max(1),
+        }
+    }
+
+    pub fn points(&self) -> u8 {
+        self.points
+    }
+
+    pub fn points_per_step(&self) -> u8 {
+        self.points_per_step
+    }
+
+    pub fn state(&self) -> MadnessState {
+        MadnessState::from_step(self.points / self.points_per_step)
+    }
+
+    /// Adds madness points, returning the character's new state.
+    pub fn gain(&mut self, points: u8) -> MadnessState {
+        self.points = self.points.saturating_add(points);
+        self.state()
+    }
+
+    /// Removes madness points. A character who has gone mad does not
+    /// recover.
+    pub fn recover(&mut self, points: u8) {
+        if self.state() != MadnessState::Mad {
+            self.points = self.points.saturating_sub(points);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intrusion_range_rises_until_triggered() {
+        let mut horror = HorrorMode::new();
+        assert!(!horror.record_roll(15));
+        assert!(!horror.record_roll(3));
+        assert_eq!(horror.intrusion_range(), 3);
+
+        assert!(horror.record_roll(3));
+        assert_eq!(horror.intrusion_range(), 1);
+    }
+
+    #[test]
+    fn madness_moves_down_the_track() {
+        let mut track = MadnessTrack::new(3);
+        assert_eq!(track.gain(2), MadnessState::Steady);
+        assert_eq!(track.gain(1), MadnessState::Shaken);
+        assert_eq!(track.gain(3), MadnessState::Traumatized);
+
+        track.recover(2);
+        assert_eq!(track.state(), MadnessState::Shaken);
+
+        assert_eq!(track.gain(u8::MAX), MadnessState::Mad);
+        track.recover(u8::MAX);
+        assert_eq!(track.state(), MadnessState::Mad);
+    }
+}
@@ -0,0 +1,161 @@
+//! Stable identifiers for entities in the model.
+//!
+//! Names are not unique, so frontends and sync layers refer to entities by
+//! these IDs instead. Each kind of entity has its own ID type so they
+//! cannot be mixed up.
+//!
+//! IDs are optional on the entities themselves. Rolled cyphers get one
+//! straight away; other entities built in code have none until the caller
+//! assigns one with their `with_id` builder. With the `serde` feature, a
+//! document saved before an entity had an ID loads with no ID.
+
+use std::fmt;
+use std::str::FromStr;
+
+use rand::Rng;
+use uuid::Uuid;
+
+macro_rules! define_id {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(transparent))]
+        pub struct $name(Uuid);
+
+        impl $name {
+            /// Generates a new random ID.
+            pub fn random(rng: &mut impl Rng) -> Self {
+                Self(uuid::Builder::from_random_bytes(rng.gen()).into_uuid())
+            }
+
+            pub fn from_uuid(uuid: Uuid) -> Self {
+                Self(uuid)
+            }
+
+            pub fn as_uuid(&self) -> &Uuid {
+                &self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = uuid::Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Uuid::from_str(s).map(Self)
+            }
+        }
+    };
+}
+
+define_id!(
+    /// Identifies a [`CharacterArc`](crate::arc::CharacterArc).
+    ArcId
+);
+
+define_id!(
+    /// Identifies a [`Community`](crate::community::Community).
+    CommunityId
+);
+
+define_id!(
+    /// Identifies a [`CraftingProject`](crate::crafting::CraftingProject).
+    CraftingProjectId
+);
+
+define_id!(
+    /// Identifies a [`Cypher`](crate::cypher::Cypher).
+    CypherId
+);
+
+define_id!(
+    /// Identifies an [`Npc`](crate::npc::Npc), including one serving as a
+    /// [`Follower`](crate::follower::Follower).
+    NpcId
+);
+
+define_id!(
+    /// Identifies a [`Vehicle`](crate::vehicle::Vehicle).
+    VehicleId
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn ids_are_random_v4_uuids() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let id = CypherId::random(&mut rng);
+        assert_eq!(id.as_uuid().get_version_num(), 4);
+        assert_ne!(id, CypherId::random(&mut rng));
+    }
+
+    #[test]
+    fn ids_round_trip_through_strings() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let id = CypherId::random(&mut rng);
+        assert_eq!(id.to_string().parse::<CypherId>().unwrap(), id);
+        assert!("not-an-id".parse::<CypherId>().is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn entities_saved_without_ids_still_load() {
+        use crate::arc::CharacterArc;
+        use crate::community::Community;
+        use crate::crafting::CraftingProject;
+        use crate::npc::Npc;
+        use crate::vehicle::Vehicle;
+
+        let npc: Npc = serde_json::from_str(
+            r#"{"name": "Bandit", "level": 2, "health": 6, "maxHealth": 6,
+                "armor": 0, "damage": 2, "modifications": []}"#,
+        )
+        .unwrap();
+        assert_eq!(npc, Npc::new("Bandit", 2));
+
+        let vehicle: Vehicle = serde_json::from_str(
+            r#"{"name": "Skiff", "level": 3, "speed": "Fast", "crew": 1, "armor": 0,
+                "health": 9, "max_health": 9, "weapons": []}"#,
+        )
+        .unwrap();
+        assert_eq!(vehicle.id, None);
+
+        let community: Community = serde_json::from_str(
+            r#"{"name": "Hollow", "rank": 2, "health": 6, "infrastructure": [],
+                "state": "Hale"}"#,
+        )
+        .unwrap();
+        assert_eq!(community.id, None);
+
+        let arc: CharacterArc = serde_json::from_str(
+            r#"{"template": "Mystery", "opening": "Who burned the library?",
+                "steps": [], "climax": null, "resolved": false,
+                "rewards": {"step": 2, "climaxSuccess": 4, "climaxFailure": 2,
+                            "resolution": 1}}"#,
+        )
+        .unwrap();
+        assert_eq!(arc.id, None);
+
+        let project: CraftingProject = serde_json::from_str(
+            r#"{"item": "Longbow", "level": 3, "requiredHours": 10, "hoursWorked": 0,
+                "materials": [], "assessed": false}"#,
+        )
+        .unwrap();
+        assert_eq!(project.id, None);
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let npc = npc.with_id(NpcId::random(&mut rng));
+        let json = serde_json::to_string(&npc).unwrap();
+        assert_eq!(serde_json::from_str::<Npc>(&json).unwrap(), npc);
+    }
+}
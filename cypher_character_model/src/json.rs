@@ -0,0 +1,90 @@
+//! JSON export in the key style external tools expect.
+//!
+//! The model serializes with snake_case keys. Deserialization also accepts
+//! camelCase spellings of multi-word fields, so documents written by
+//! JavaScript tools can be read back without conversion.
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// The spelling used for object keys when exporting JSON.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum JsonStyle {
+    /// Keys as the model names them, such as `xp_gained`.
+    #[default]
+    SnakeCase,
+    /// Keys as JavaScript tools usually expect them, such as `xpGained`.
+    CamelCase,
+}
+
+/// Serializes a value to JSON, with object keys in the given style.
+pub fn to_value<T: Serialize + ?Sized>(value: &T, style: JsonStyle) -> serde_json::Result<Value> {
+    let value = serde_json::to_value(value)?;
+    Ok(match style {
+        JsonStyle::SnakeCase => value,
+        JsonStyle::CamelCase => to_camel_case_keys(value),
+    })
+}
+
+/// Serializes a value to a JSON string, with object keys in the given style.
+pub fn to_string<T: Serialize + ?Sized>(value: &T, style: JsonStyle) -> serde_json::Result<String> {
+    serde_json::to_string(&to_value(value, style)?)
+}
+
+fn to_camel_case_keys(value: Value) -> Value {
+    match value {
+        Value::Object(object) => Value::Object(
+            object
+                .into_iter()
+                .map(|(key, value)| (to_camel_case(&key), to_camel_case_keys(value)))
+                .collect::<Map<_, _>>(),
+        ),
+        Value::Array(values) => Value::Array(values.into_iter().map(to_camel_case_keys).collect()),
+        value => value,
+    }
+}
+
+fn to_camel_case(key: &str) -> String {
+    let mut camel = String::with_capacity(key.len());
+    let mut upper = false;
+    for c in key.chars() {
+        if c == '_' {
+            upper = !camel.is_empty();
+        } else if upper {
+            camel.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            camel.push(c);
+        }
+    }
+    camel
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session::{SessionEntry, SessionLog};
+
+    #[test]
+    fn camel_case_converts_keys() {
+        assert_eq!(to_camel_case("intrusions_accepted"), "intrusionsAccepted");
+        assert_eq!(to_camel_case("level"), "level");
+    }
+
+    #[test]
+    fn both_styles_round_trip() {
+        let mut log = SessionLog::new();
+        log.append(SessionEntry {
+            xp_gained: 4,
+            ..SessionEntry::new("2024-03-02")
+        });
+
+        let snake = to_string(&log, JsonStyle::SnakeCase).unwrap();
+        let camel = to_string(&log, JsonStyle::CamelCase).unwrap();
+        assert!(snake.contains("\"xp_gained\":4"));
+        assert!(camel.contains("\"xpGained\":4"));
+
+        assert_eq!(serde_json::from_str::<SessionLog>(&snake).unwrap(), log);
+        assert_eq!(serde_json::from_str::<SessionLog>(&camel).unwrap(), log);
+    }
+}
@@ -0,0 +1,184 @@
+//! A double-entry ledger of XP and currency changes.
+
+use std::error::Error;
+use std::fmt;
+
+/// Something that can be recorded in a [`Ledger`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Resource {
+    Xp,
+    /// An amount of the named currency.
+    Currency(String),
+}
+
+/// A transfer of a resource from one account to another.
+///
+/// Accounts are named by the caller, and can be characters ("Kira") or
+/// sources and sinks outside the table ("GM", "market").
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LedgerEntry {
+    pub from: String,
+    pub to: String,
+    pub resource: Resource,
+    pub amount: u64,
+    pub reason: String,
+}
+
+/// Returned when an account would spend more than it holds.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct InsufficientBalanceError {
+    pub account: String,
+    pub balance: i128,
+    pub amount: u64,
+}
+
+impl fmt::Display for InsufficientBalanceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} has a balance of {} but tried to spend {}",
+            self.account, self.balance, self.amount
+        )
+    }
+}
+
+impl Error for InsufficientBalanceError {}
+
+/// An append-only record of XP and currency moving between accounts.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Ledger {
+    entries: Vec<LedgerEntry>,
+}
+
+impl Ledger {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a transfer without checking the sender's balance, for
+    /// sources outside the table such as the GM awarding XP.
+    pub fn grant(
+        &mut self,
+        from: impl Into<String>,
+        to: impl Into<String>,
+        resource: Resource,
+        amount: u64,
+        reason: impl Into<String>,
+    ) {
+        self.entries.push(LedgerEntry {
+            from: from.into(),
+            to: to.into(),
+            resource,
+            amount,
+            reason: reason.into(),
+        });
+    }
+
+    /// Records a transfer, failing if the sender does not hold enough.
+    pub fn transfer(
+        &mut self,
+        from: impl Into<String>,
+        to: impl Into<String>,
+        resource: Resource,
+        amount: u64,
+        reason: impl Into<String>,
+    ) -> Result<(), InsufficientBalanceError> {
+        let from = from.into();
+        let balance = self.balance(&from, &resource);
+        if balance < i128::from(amount) {
+            return Err(InsufficientBalanceError {
+                account: from,
+                balance,
+                amount,
+            });
+        }
+        self.grant(from, to, resource, amount, reason);
+        Ok(())
+    }
+
+    /// The net amount of a resource the given account holds.
+    ///
+    /// Balances are kept as `i128` so that any number of full-size `u64`
+    /// grants can be summed without overflowing.
+    pub fn balance(&self, account: &str, resource: &Resource) -> i128 {
+        self.entries
+            .iter()
+            .filter(|entry| &entry.resource == resource)
+            .map(|entry| {
+                let amount = i128::from(entry.amount);
+                match (entry.from == account, entry.to == account) {
+                    (true, false) => -amount,
+                    (false, true) => amount,
+                    _ => 0,
+                }
+            })
+            .fold(0, i128::saturating_add)
+    }
+
+    /// Every entry involving the given account, oldest first.
+    pub fn history<'a>(&'a self, account: &'a str) -> impl Iterator<Item = &'a LedgerEntry> {
+        self.entries
+            .iter()
+            .filter(move |entry| entry.from == account || entry.to == account)
+    }
+
+    pub fn entries(&self) -> &[LedgerEntry] {
+        &self.entries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intrusion_xp_can_be_shared() {
+        let mut ledger = Ledger::new();
+        ledger.grant("GM", "Kira", Resource::Xp, 2, "accepted GM intrusion");
+        ledger
+            .transfer("Kira", "Bo", Resource::Xp, 1, "shared intrusion XP")
+            .unwrap();
+
+        assert_eq!(ledger.balance("Kira", &Resource::Xp), 1);
+        assert_eq!(ledger.balance("Bo", &Resource::Xp), 1);
+        assert_eq!(ledger.balance("GM", &Resource::Xp), -2);
+        assert_eq!(ledger.history("Kira").count(), 2);
+    }
+
+    #[test]
+    fn transfer_checks_balance() {
+        let mut ledger = Ledger::new();
+        let shins = Resource::Currency("shins".to_string());
+        ledger.grant("loot", "Kira", shins.clone(), 5, "found in the ruin");
+
+        assert_eq!(
+            ledger.transfer("Kira", "market", shins.clone(), 6, "rope"),
+            Err(InsufficientBalanceError {
+                account: "Kira".to_string(),
+                balance: 5,
+                amount: 6
+            })
+        );
+        assert_eq!(ledger.balance("Kira", &Resource::Xp), 0);
+    }
+
+    #[test]
+    fn huge_amounts_do_not_wrap() {
+        let mut ledger = Ledger::new();
+        assert!(ledger
+            .transfer("Kira", "Bo", Resource::Xp, u64::MAX, "overspend")
+            .is_err());
+        assert_eq!(ledger.balance("Kira", &Resource::Xp), 0);
+
+        ledger.grant("GM", "Kira", Resource::Xp, u64::MAX, "windfall");
+        ledger.grant("GM", "Kira", Resource::Xp, u64::MAX, "windfall");
+        assert_eq!(
+            ledger.balance("Kira", &Resource::Xp),
+            2 * i128::from(u64::MAX)
+        );
+        ledger
+            .transfer("Kira", "Bo", Resource::Xp, u64::MAX, "gift")
+            .unwrap();
+        assert_eq!(ledger.balance("Bo", &Resource::Xp), i128::from(u64::MAX));
+    }
+}
@@ -1,3 +1,30 @@
+pub mod allocation;
+pub mod arc;
+pub mod asset;
+pub mod benefit;
+pub mod community;
+pub mod condition;
+pub mod crafting;
+pub mod cypher;
+pub mod damage;
+pub mod effort;
+pub mod encounter;
+pub mod follower;
+pub mod horror;
+pub mod id;
+#[cfg(feature = "serde")]
+pub mod json;
+pub mod ledger;
+pub mod mutation;
+pub mod npc;
+pub mod power_shift;
+pub mod pronouns;
+pub mod range;
+pub mod session;
+pub mod skill;
+pub mod vehicle;
+pub mod wealth;
+
 pub fn add(left: usize, right: usize) -> usize {
     left + right
 }
@@ -0,0 +1,149 @@
+//! Mutations for mutant characters, and the tables used to roll them.
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::effort::EffortType;
+
+/// The broad kind of a mutation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MutationKind {
+    Beneficial,
+    Harmful,
+    /// A purely cosmetic change.
+    Distinctive,
+    Powerful,
+}
+
+/// What a mutation does to the character who has it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MutationEffect {
+    /// Raises or lowers the maximum of a Pool.
+    Pool { pool: EffortType, amount: i8 },
+    /// Grants training in a skill.
+    Trained(String),
+    /// Hinders tasks of the named kind by one step.
+    Hindered(String),
+    /// Grants Armor.
+    Armor(u8),
+    /// Has no mechanical effect beyond its description.
+    Descriptive,
+}
+
+/// A mutation a character has.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Mutation {
+    pub name: String,
+    pub kind: MutationKind,
+    pub effect: MutationEffect,
+}
+
+impl Mutation {
+    pub fn new(name: impl Into<String>, kind: MutationKind, effect: MutationEffect) -> Self {
+        Self {
+            name: name.into(),
+            kind,
+            effect,
+        }
+    }
+
+    /// Rolls a mutation of the given kind from the core tables.
+    pub fn random(kind: MutationKind, rng: &mut impl Rng) -> Self {
+        MutationTable::core(kind)
+            .roll(rng)
+            .expect("the core mutation tables are not empty")
+    }
+}
+
+/// A table of mutations to roll on.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MutationTable {
+    entries: Vec<Mutation>,
+}
+
+impl MutationTable {
+    pub fn new(entries: Vec<Mutation>) -> Self {
+        Self { entries }
+    }
+
+    /// The built-in table for the given kind of mutation.
+    pub fn core(kind: MutationKind) -> Self {
+        use EffortType::{Intellect, Might, Speed};
+        use MutationEffect::{Armor, Descriptive, Hindered, Trained};
+
+        let pool = |pool, amount| MutationEffect::Pool { pool, amount };
+        let entries = match kind {
+            MutationKind::Beneficial => vec![
+                ("Dense muscle", pool(Might, 2)),
+                ("Quick reflexes", pool(Speed, 2)),
+                ("Expanded mind", pool(Intellect, 2)),
+                ("Keen senses", Trained("perception".to_string())),
+                ("Climbing limbs", Trained("climbing".to_string())),
+            ],
+            MutationKind::Harmful => vec![
+                ("Brittle bones", pool(Might, -2)),
+                ("Sluggish nerves", pool(Speed, -2)),
+                ("Clouded thoughts", pool(Intellect, -2)),
+                ("Weak eyes", Hindered("perception".to_string())),
+                ("Unsettling presence", Hindered("persuasion".to_string())),
+            ],
+            MutationKind::Distinctive => vec![
+                ("Glowing eyes", Descriptive),
+                ("Iridescent skin", Descriptive),
+                ("Extra fingers", Descriptive),
+                ("Hair of feathers", Descriptive),
+                ("Faint metallic scent", Descriptive),
+            ],
+            MutationKind::Powerful => vec![
+                ("Chitinous plates", Armor(1)),
+                ("Massive frame", pool(Might, 4)),
+                ("Blurring speed", pool(Speed, 4)),
+                ("Vast intellect", pool(Intellect, 4)),
+            ],
+        };
+
+        Self::new(
+            entries
+                .into_iter()
+                .map(|(name, effect)| Mutation::new(name, kind, effect))
+                .collect(),
+        )
+    }
+
+    pub fn entries(&self) -> &[Mutation] {
+        &self.entries
+    }
+
+    /// Rolls a mutation from this table, or `None` if the table is empty.
+    pub fn roll(&self, rng: &mut impl Rng) -> Option<Mutation> {
+        self.entries.choose(rng).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn random_mutations_match_the_requested_kind() {
+        let mut rng = StdRng::seed_from_u64(0);
+        for kind in [
+            MutationKind::Beneficial,
+            MutationKind::Harmful,
+            MutationKind::Distinctive,
+            MutationKind::Powerful,
+        ] {
+            let mutation = Mutation::random(kind, &mut rng);
+            assert_eq!(mutation.kind, kind);
+            assert!(MutationTable::core(kind).entries().contains(&mutation));
+        }
+    }
+
+    #[test]
+    fn empty_table_rolls_nothing() {
+        let mut rng = StdRng::seed_from_u64(0);
+        assert_eq!(MutationTable::new(Vec::new()).roll(&mut rng), None);
+    }
+}
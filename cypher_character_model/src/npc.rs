@@ -0,0 +1,197 @@
+//! Non-player characters and creatures.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::damage;
+use crate::id::NpcId;
+
+/// A task that an NPC performs as if it were a different level, as in
+/// "attacks as level 5".
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Modification {
+    pub task: String,
+    pub level: u8,
+}
+
+impl Modification {
+    pub fn new(task: impl Into<String>, level: u8) -> Self {
+        Self {
+            task: task.into(),
+            level,
+        }
+    }
+}
+
+/// A non-player character or creature.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Npc {
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub id: Option<NpcId>,
+    pub name: String,
+    pub level: u8,
+    pub health: u8,
+    #[cfg_attr(feature = "serde", serde(alias = "maxHealth"))]
+    pub max_health: u8,
+    pub armor: u8,
+    pub damage: u8,
+    pub modifications: Vec<Modification>,
+}
+
+impl Npc {
+    /// Creates an NPC with the standard health (equal to its target number)
+    /// and damage (equal to its level) for the given level.
+    pub fn new(name: impl Into<String>, level: u8) -> Self {
+        let max_health = level.saturating_mul(3);
+        Self {
+            id: None,
+            name: name.into(),
+            level,
+            health: max_health,
+            max_health,
+            armor: 0,
+            damage: level,
+            modifications: Vec::new(),
+        }
+    }
+
+    pub fn with_id(mut self, id: NpcId) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn with_armor(mut self, armor: u8) -> Self {
+        self.armor = armor;
+        self
+    }
+
+    pub fn with_damage(mut self, damage: u8) -> Self {
+        self.damage = damage;
+        self
+    }
+
+    pub fn with_health(mut self, health: u8) -> Self {
+        self.health = health;
+        self.max_health = health;
+        self
+    }
+
+    pub fn with_modification(mut self, modification: Modification) -> Self {
+        self.modifications.push(modification);
+        self
+    }
+
+    /// The number a character must roll to succeed on a task against this NPC.
+    pub fn target_number(&self) -> u8 {
+        self.level.saturating_mul(3)
+    }
+
+    /// The level this NPC acts at for the given task, taking modifications
+    /// into account.
+    pub fn level_for(&self, task: &str) -> u8 {
+        self.modifications
+            .iter()
+            .find(|modification| modification.task == task)
+            .map_or(self.level, |modification| modification.level)
+    }
+
+    /// The target number for a task against this NPC of the given kind.
+    pub fn target_number_for(&self, task: &str) -> u8 {
+        self.level_for(task).saturating_mul(3)
+    }
+
+    /// Deals damage to this NPC, reduced by its Armor. Returns the damage
+    /// actually taken.
+    pub fn take_damage(&mut self, damage: u8) -> u8 {
+        damage::apply(&mut self.health, self.armor, damage)
+    }
+
+    pub fn is_defeated(&self) -> bool {
+        self.health == 0
+    }
+
+    /// Returns a copy of this NPC rescaled to the given level.
+    ///
+    /// Health and damage scale in proportion to the change in level, so a
+    /// creature that was tougher or hit harder than standard for its level
+    /// stays that way. Modified tasks keep the same offset from the NPC's
+    /// level, and Armor is unchanged. The copy starts at full health and has
+    /// no ID.
+    pub fn scaled_to(&self, level: u8) -> Self {
+        let scale = |value: u8, standard: u8| -> u8 {
+            if self.level == 0 {
+                standard
+            } else {
+                let scaled = u32::from(value) * u32::from(level) / u32::from(self.level);
+                scaled.min(u32::from(u8::MAX)) as u8
+            }
+        };
+        let max_health = scale(self.max_health, level.saturating_mul(3));
+        let modifications = self
+            .modifications
+            .iter()
+            .map(|modification| {
+                let offset = i16::from(modification.level) - i16::from(self.level);
+                let scaled = (i16::from(level) + offset).clamp(0, i16::from(u8::MAX));
+                Modification::new(modification.task.clone(), scaled as u8)
+            })
+            .collect();
+
+        Self {
+            id: None,
+            name: self.name.clone(),
+            level,
+            health: max_health,
+            max_health,
+            armor: self.armor,
+            damage: scale(self.damage, level),
+            modifications,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_uses_standard_stats() {
+        let npc = Npc::new("Guard", 3);
+        assert_eq!(npc.target_number(), 9);
+        assert_eq!(npc.health, 9);
+        assert_eq!(npc.damage, 3);
+    }
+
+    #[test]
+    fn modifications_change_task_level() {
+        let npc = Npc::new("Sniper", 3).with_modification(Modification::new("attack", 5));
+        assert_eq!(npc.target_number_for("attack"), 15);
+        assert_eq!(npc.target_number_for("stealth"), 9);
+    }
+
+    #[test]
+    fn scaled_to_keeps_relative_stats() {
+        let brute = Npc::new("Brute", 4)
+            .with_health(20)
+            .with_armor(1)
+            .with_modification(Modification::new("attack", 5));
+        let scaled = brute.scaled_to(6);
+
+        assert_eq!(scaled.target_number(), 18);
+        assert_eq!(scaled.health, 30);
+        assert_eq!(scaled.damage, 6);
+        assert_eq!(scaled.armor, 1);
+        assert_eq!(scaled.level_for("attack"), 7);
+        assert_eq!(Npc::new("Rat", 1).scaled_to(3), Npc::new("Rat", 3));
+    }
+
+    #[test]
+    fn armor_reduces_damage() {
+        let mut npc = Npc::new("Golem", 2).with_armor(2);
+        assert_eq!(npc.take_damage(5), 3);
+        assert_eq!(npc.take_damage(10), 3);
+        assert!(npc.is_defeated());
+    }
+}
@@ -0,0 +1,112 @@
+//! Power shifts for superhero-tier play.
+
+use std::error::Error;
+use std::fmt;
+
+/// An area in which a power shift permanently eases tasks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PowerShiftCategory {
+    Accuracy,
+    Dexterity,
+    Healing,
+    Intelligence,
+    Resilience,
+    SingleAttack,
+    Strength,
+}
+
+impl fmt::Display for PowerShiftCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            PowerShiftCategory::Accuracy => "Accuracy",
+            PowerShiftCategory::Dexterity => "Dexterity",
+            PowerShiftCategory::Healing => "Healing",
+            PowerShiftCategory::Intelligence => "Intelligence",
+            PowerShiftCategory::Resilience => "Resilience",
+            PowerShiftCategory::SingleAttack => "Single Attack",
+            PowerShiftCategory::Strength => "Strength",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Returned when a character would have more power shifts than the
+/// campaign allows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TooManyPowerShiftsError {
+    pub allowed: u8,
+}
+
+impl fmt::Display for TooManyPowerShiftsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the campaign allows only {} power shifts", self.allowed)
+    }
+}
+
+impl Error for TooManyPowerShiftsError {}
+
+/// The power shifts a character has, limited to the number the campaign
+/// allows.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PowerShifts {
+    allowed: u8,
+    shifts: Vec<PowerShiftCategory>,
+}
+
+impl PowerShifts {
+    pub fn new(allowed: u8) -> Self {
+        Self {
+            allowed,
+            shifts: Vec::new(),
+        }
+    }
+
+    /// Adds a shift in the given category. A category may be shifted more
+    /// than once.
+    pub fn add(&mut self, category: PowerShiftCategory) -> Result<(), TooManyPowerShiftsError> {
+        if self.total() >= self.allowed {
+            return Err(TooManyPowerShiftsError {
+                allowed: self.allowed,
+            });
+        }
+        self.shifts.push(category);
+        Ok(())
+    }
+
+    pub fn allowed(&self) -> u8 {
+        self.allowed
+    }
+
+    /// The total number of shifts taken across all categories.
+    pub fn total(&self) -> u8 {
+        self.shifts.len() as u8
+    }
+
+    /// The number of steps tasks in the given category are eased by.
+    pub fn easing_for(&self, category: PowerShiftCategory) -> u8 {
+        self.shifts
+            .iter()
+            .filter(|&&shift| shift == category)
+            .count() as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shifts_ease_tasks_up_to_the_limit() {
+        let mut shifts = PowerShifts::new(3);
+        shifts.add(PowerShiftCategory::Strength).unwrap();
+        shifts.add(PowerShiftCategory::Strength).unwrap();
+        shifts.add(PowerShiftCategory::Healing).unwrap();
+        assert_eq!(
+            shifts.add(PowerShiftCategory::Accuracy),
+            Err(TooManyPowerShiftsError { allowed: 3 })
+        );
+
+        assert_eq!(shifts.easing_for(PowerShiftCategory::Strength), 2);
+        assert_eq!(shifts.easing_for(PowerShiftCategory::Accuracy), 0);
+    }
+}
@@ -0,0 +1,419 @@
+//! Structured pronouns, so generated text can refer to a character correctly.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// A single set of pronouns, such as she/her/her/herself.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PronounSet {
+    pub subject: String,
+    pub object: String,
+    pub possessive: String,
+    pub reflexive: String,
+}
+
+impl PronounSet {
+    /// Creates a set whose reflexive form is the object form followed by
+    /// "self", as in "xemself".
+    pub fn new(
+        subject: impl Into<String>,
+        object: impl Into<String>,
+        possessive: impl Into<String>,
+    ) -> Self {
+        let object = object.into();
+        Self {
+            subject: subject.into(),
+            reflexive: format!("{object}self"),
+            object,
+            possessive: possessive.into(),
+        }
+    }
+
+    pub fn with_reflexive(mut self, reflexive: impl Into<String>) -> Self {
+        self.reflexive = reflexive.into();
+        self
+    }
+
+    pub fn she() -> Self {
+        Self::new("she", "her", "her")
+    }
+
+    pub fn he() -> Self {
+        Self::new("he", "him", "his")
+    }
+
+    pub fn they() -> Self {
+        Self::new("they", "them", "their")
+    }
+
+    pub fn it() -> Self {
+        Self::new("it", "it", "its").with_reflexive("itself")
+    }
+
+    fn known() -> [Self; 4] {
+        [Self::she(), Self::he(), Self::they(), Self::it()]
+    }
+
+    /// Whether verbs following the subject form take plural agreement,
+    /// as in "they are" rather than "she is".
+    pub fn is_plural(&self) -> bool {
+        self.subject == "they"
+    }
+
+    /// Whether the given form belongs to this set, including independent
+    /// possessives such as "hers" and "theirs" and plural reflexives such as
+    /// "themselves".
+    fn contains(&self, form: &str) -> bool {
+        self.subject == form
+            || self.object == form
+            || self.possessive == form
+            || self.reflexive == form
+            || form.strip_suffix('s') == Some(self.possessive.as_str())
+            || form.strip_suffix("selves") == Some(self.object.as_str())
+    }
+
+    fn is_known(&self) -> bool {
+        Self::known().contains(self)
+    }
+
+    fn has_default_reflexive(&self) -> bool {
+        self.reflexive.strip_suffix("self") == Some(self.object.as_str())
+    }
+}
+
+/// Whether a form is a reflexive, such as "herself" or "themselves".
+fn is_reflexive(form: &str) -> bool {
+    form.ends_with("self") || form.ends_with("selves")
+}
+
+/// Words that show a pronoun string is a preference rather than a list of
+/// pronoun sets, as in "any" or "ask".
+const FREEFORM_WORDS: [&str; 5] = ["any", "all", "ask", "none", "name"];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Source {
+    Sets,
+    /// Parsed from the given text, which is kept so it can be written back
+    /// exactly as it was typed.
+    Parsed(String),
+    /// Text that does not list pronoun sets.
+    Freeform(String),
+}
+
+/// One or more sets of pronouns that a character uses, in order of preference.
+///
+/// Pronouns parsed from text remember that text, and display as it. Text
+/// that is a preference rather than a list of pronoun sets, such as "any"
+/// or "ask me", is kept as freeform pronouns, which use they/them when
+/// generating text.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Pronouns {
+    sets: Vec<PronounSet>,
+    source: Source,
+}
+
+impl Pronouns {
+    /// Creates pronouns from the given sets, returning `None` if there are none.
+    pub fn new(sets: Vec<PronounSet>) -> Option<Self> {
+        if sets.is_empty() {
+            None
+        } else {
+            Some(Self {
+                sets,
+                source: Source::Sets,
+            })
+        }
+    }
+
+    /// Creates pronouns from text that does not list pronoun sets.
+    pub fn freeform(text: impl Into<String>) -> Self {
+        Self {
+            sets: vec![PronounSet::they()],
+            source: Source::Freeform(text.into()),
+        }
+    }
+
+    pub fn is_freeform(&self) -> bool {
+        matches!(self.source, Source::Freeform(_))
+    }
+
+    /// The preferred set, used when generating text.
+    pub fn primary(&self) -> &PronounSet {
+        &self.sets[0]
+    }
+
+    pub fn sets(&self) -> &[PronounSet] {
+        &self.sets
+    }
+}
+
+impl From<PronounSet> for Pronouns {
+    fn from(set: PronounSet) -> Self {
+        Self {
+            sets: vec![set],
+            source: Source::Sets,
+        }
+    }
+}
+
+impl fmt::Display for Pronouns {
+    /// Writes parsed and freeform pronouns as the text they came from.
+    /// Otherwise formats a single common set as "she/her" and multiple sets
+    /// as "she/they". Sets other than the common ones have all their forms
+    /// written, as in "xe/xem/xyr/they", so the result parses back to the
+    /// same sets.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.source {
+            Source::Parsed(text) | Source::Freeform(text) => return f.write_str(text),
+            Source::Sets => {}
+        }
+        if let [set] = self.sets.as_slice() {
+            if set.is_known() {
+                return write!(f, "{}/{}", set.subject, set.object);
+            }
+        }
+        for (i, set) in self.sets.iter().enumerate() {
+            if i > 0 {
+                f.write_str("/")?;
+            }
+            if set.is_known() {
+                f.write_str(&set.subject)?;
+            } else {
+                write!(f, "{}/{}/{}", set.subject, set.object, set.possessive)?;
+                if !set.has_default_reflexive() {
+                    write!(f, "/{}", set.reflexive)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returned when a string contains no pronouns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EmptyPronounsError;
+
+impl fmt::Display for EmptyPronounsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("no pronouns given")
+    }
+}
+
+impl Error for EmptyPronounsError {}
+
+impl FromStr for Pronouns {
+    type Err = EmptyPronounsError;
+
+    /// Parses slash-separated pronouns such as "she/her", "she/they" or
+    /// "xe/xem/xyr/xemself". Forms of a set that follow its subject are
+    /// folded into that set; unrecognized forms are read as subject, object
+    /// and possessive of a new set, followed by an optional reflexive.
+    /// Preferences such as "any" or "ask me" are kept as freeform text.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let forms: Vec<String> = s
+            .split('/')
+            .map(|form| form.trim().to_lowercase())
+            .filter(|form| !form.is_empty())
+            .collect();
+        if forms.is_empty() {
+            return Err(EmptyPronounsError);
+        }
+        if forms.iter().any(|form| {
+            form.contains(char::is_whitespace) || FREEFORM_WORDS.contains(&form.as_str())
+        }) {
+            return Ok(Pronouns::freeform(s));
+        }
+
+        let is_known_subject =
+            |form: &str| PronounSet::known().iter().any(|set| set.subject == form);
+        let mut sets: Vec<PronounSet> = Vec::new();
+        let mut i = 0;
+        while i < forms.len() {
+            let form = &forms[i];
+            if sets.last().is_some_and(|set| set.contains(form)) {
+                i += 1;
+            } else if let Some(set) = sets
+                .last_mut()
+                .filter(|set| is_reflexive(form) && !set.is_known())
+            {
+                set.reflexive = form.clone();
+                i += 1;
+            } else if let Some(set) = PronounSet::known()
+                .into_iter()
+                .find(|set| &set.subject == form)
+            {
+                sets.push(set);
+                i += 1;
+            } else {
+                let custom: Vec<&String> = forms[i..]
+                    .iter()
+                    .take_while(|form| !is_known_subject(form) && !is_reflexive(form))
+                    .take(3)
+                    .collect();
+                if custom.is_empty() {
+                    // A reflexive with no set before it.
+                    return Ok(Pronouns::freeform(s));
+                }
+                let object = custom.get(1).unwrap_or(&custom[0]);
+                let possessive = custom.get(2).unwrap_or(object);
+                sets.push(PronounSet::new(custom[0], *object, *possessive));
+                i += custom.len();
+            }
+        }
+
+        Ok(Self {
+            sets,
+            source: Source::Parsed(s.to_string()),
+        })
+    }
+}
+
+/// Pronouns are stored in their string form, such as "she/they", which
+/// matches the plain `pronouns` strings of older documents. Loading and
+/// saving a document writes back the same string, including strings that
+/// are empty or freeform.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Pronouns {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Pronouns {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or_else(|_| Pronouns::freeform(s)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_displays_common_forms() {
+        for text in ["she/her", "he/him", "they/them", "she/they", "he/she/they"] {
+            let pronouns: Pronouns = text.parse().unwrap();
+            assert_eq!(pronouns.to_string(), text);
+        }
+
+        let pronouns: Pronouns = "He/Him/His".parse().unwrap();
+        assert_eq!(pronouns.sets(), [PronounSet::he()]);
+
+        let pronouns: Pronouns = "she/her/hers".parse().unwrap();
+        assert_eq!(pronouns.sets(), [PronounSet::she()]);
+    }
+
+    #[test]
+    fn parses_multiple_sets() {
+        let pronouns: Pronouns = "she/they".parse().unwrap();
+        assert_eq!(pronouns.sets(), [PronounSet::she(), PronounSet::they()]);
+        assert!(!pronouns.primary().is_plural());
+    }
+
+    #[test]
+    fn parses_unrecognized_sets() {
+        let pronouns: Pronouns = "xe/xem/xyr".parse().unwrap();
+        assert_eq!(pronouns.primary(), &PronounSet::new("xe", "xem", "xyr"));
+        assert_eq!(pronouns.to_string(), "xe/xem/xyr");
+    }
+
+    #[test]
+    fn mixed_sets_round_trip() {
+        for text in [
+            "xe/xem/xyr/they",
+            "she/ze/hir/hir",
+            "ey/em/eir/he/xe/xem/xyr",
+            "she/her/hers/herself",
+            "he/him/his/himself",
+            "they/them/their/theirs/themself",
+            "ze/hir/hir/hirs/hirself",
+            "xe/xem/xyr/xyrs/xemself",
+        ] {
+            let pronouns: Pronouns = text.parse().unwrap();
+            assert_eq!(pronouns.to_string(), text);
+            assert_eq!(pronouns.to_string().parse::<Pronouns>().unwrap(), pronouns);
+
+            let rebuilt = Pronouns::new(pronouns.sets().to_vec()).unwrap();
+            assert_eq!(
+                rebuilt.to_string().parse::<Pronouns>().unwrap().sets(),
+                pronouns.sets()
+            );
+        }
+
+        let pronouns: Pronouns = "xe/xem/xyr/they".parse().unwrap();
+        assert_eq!(
+            pronouns.sets(),
+            [PronounSet::new("xe", "xem", "xyr"), PronounSet::they()]
+        );
+    }
+
+    #[test]
+    fn reflexives_belong_to_the_set_before_them() {
+        let pronouns: Pronouns = "she/her/hers/herself".parse().unwrap();
+        assert_eq!(pronouns.sets(), [PronounSet::she()]);
+
+        let pronouns: Pronouns = "they/them/themselves".parse().unwrap();
+        assert_eq!(pronouns.sets(), [PronounSet::they()]);
+
+        let pronouns: Pronouns = "xe/xem/xyr/xyrself/she".parse().unwrap();
+        assert_eq!(
+            pronouns.sets(),
+            [
+                PronounSet::new("xe", "xem", "xyr").with_reflexive("xyrself"),
+                PronounSet::she()
+            ]
+        );
+        let rebuilt = Pronouns::new(pronouns.sets().to_vec()).unwrap();
+        assert_eq!(rebuilt.to_string(), "xe/xem/xyr/xyrself/she");
+    }
+
+    #[test]
+    fn preferences_are_kept_as_freeform_text() {
+        for text in ["any", "any/all", "Ask me", "he/any"] {
+            let pronouns: Pronouns = text.parse().unwrap();
+            assert!(pronouns.is_freeform());
+            assert_eq!(pronouns.to_string(), text);
+            assert_eq!(pronouns.primary(), &PronounSet::they());
+        }
+    }
+
+    #[test]
+    fn rejects_empty_string() {
+        assert_eq!(" / ".parse::<Pronouns>(), Err(EmptyPronounsError));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serializes_as_a_string() {
+        let pronouns: Pronouns = "xe/xem/xyr/they".parse().unwrap();
+        let json = serde_json::to_string(&pronouns).unwrap();
+        assert_eq!(json, r#""xe/xem/xyr/they""#);
+        assert_eq!(serde_json::from_str::<Pronouns>(&json).unwrap(), pronouns);
+
+        let pronouns: Pronouns = serde_json::from_str(r#""She/Her""#).unwrap();
+        assert_eq!(pronouns.sets(), [PronounSet::she()]);
+
+        let built = Pronouns::from(PronounSet::he());
+        assert_eq!(serde_json::to_string(&built).unwrap(), r#""he/him""#);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn legacy_strings_are_written_back_unchanged() {
+        for json in [
+            r#""""#,
+            r#""any""#,
+            r#""any/all""#,
+            r#""She/Her""#,
+            r#""they / them""#,
+            r#""she/her/hers/herself""#,
+            r#""ask me""#,
+        ] {
+            let pronouns: Pronouns = serde_json::from_str(json).unwrap();
+            assert_eq!(serde_json::to_string(&pronouns).unwrap(), json);
+        }
+    }
+}
@@ -0,0 +1,108 @@
+//! Distances, as the rules measure them in range bands.
+
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A range band.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Range {
+    /// Within reach, or a few steps away: about 10 feet (3 m).
+    Immediate,
+    /// About 50 feet (15 m).
+    Short,
+    /// About 100 feet (30 m).
+    Long,
+    /// About 500 feet (150 m).
+    VeryLong,
+}
+
+/// What it takes for a character to move a given distance in a round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Movement {
+    /// The move can be made as part of another action.
+    PartOfAnotherAction,
+    /// The move takes the character's whole action.
+    Action,
+    /// The move takes the character's whole action and may require a Speed
+    /// task to avoid stumbling or falling.
+    ActionWithSpeedTask,
+    /// The distance cannot be covered in a single round.
+    MultipleRounds,
+}
+
+impl Range {
+    pub const ALL: [Range; 4] = [Range::Immediate, Range::Short, Range::Long, Range::VeryLong];
+
+    /// The approximate distance in feet.
+    pub fn feet(self) -> u32 {
+        match self {
+            Range::Immediate => 10,
+            Range::Short => 50,
+            Range::Long => 100,
+            Range::VeryLong => 500,
+        }
+    }
+
+    /// The approximate distance in meters.
+    pub fn meters(self) -> u32 {
+        match self {
+            Range::Immediate => 3,
+            Range::Short => 15,
+            Range::Long => 30,
+            Range::VeryLong => 150,
+        }
+    }
+
+    /// What it takes to move this distance in a single round.
+    pub fn movement(self) -> Movement {
+        match self {
+            Range::Immediate => Movement::PartOfAnotherAction,
+            Range::Short => Movement::Action,
+            Range::Long => Movement::ActionWithSpeedTask,
+            Range::VeryLong => Movement::MultipleRounds,
+        }
+    }
+
+    /// Whether a target at this distance can be reached by a weapon or
+    /// ability with the given maximum range.
+    pub fn is_within(self, max: Range) -> bool {
+        self <= max
+    }
+}
+
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Range::Immediate => "immediate",
+            Range::Short => "short",
+            Range::Long => "long",
+            Range::VeryLong => "very long",
+        };
+        f.write_str(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ranges_are_ordered() {
+        assert!(Range::Immediate.is_within(Range::Short));
+        assert!(Range::Long.is_within(Range::Long));
+        assert!(!Range::VeryLong.is_within(Range::Long));
+        assert!(Range::ALL
+            .windows(2)
+            .all(|pair| pair[0].feet() < pair[1].feet()));
+    }
+
+    #[test]
+    fn movement_by_range() {
+        assert_eq!(Range::Immediate.movement(), Movement::PartOfAnotherAction);
+        assert_eq!(Range::Short.movement(), Movement::Action);
+        assert_eq!(Range::VeryLong.movement(), Movement::MultipleRounds);
+    }
+}
@@ -0,0 +1,180 @@
+//! A log of play sessions for a character.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::cypher::Cypher;
+
+/// What happened to a character during one session.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SessionEntry {
+    /// The date of the session, as an ISO 8601 date such as "2024-03-02".
+    pub date: String,
+    #[cfg_attr(feature = "serde", serde(alias = "xpGained"))]
+    pub xp_gained: u32,
+    #[cfg_attr(feature = "serde", serde(alias = "intrusionsAccepted"))]
+    pub intrusions_accepted: u32,
+    #[cfg_attr(feature = "serde", serde(alias = "cyphersFound"))]
+    pub cyphers_found: Vec<Cypher>,
+    /// The names of the cyphers used.
+    #[cfg_attr(feature = "serde", serde(alias = "cyphersUsed"))]
+    pub cyphers_used: Vec<String>,
+    pub notes: String,
+}
+
+impl SessionEntry {
+    pub fn new(date: impl Into<String>) -> Self {
+        Self {
+            date: date.into(),
+            ..Self::default()
+        }
+    }
+}
+
+/// Every session a character has played, in the order they were added.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct SessionLog {
+    sessions: Vec<SessionEntry>,
+}
+
+impl SessionLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn append(&mut self, session: SessionEntry) {
+        self.sessions.push(session);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &SessionEntry> {
+        self.sessions.iter()
+    }
+
+    pub fn latest(&self) -> Option<&SessionEntry> {
+        self.sessions.last()
+    }
+
+    /// The sessions played between the given ISO 8601 dates, inclusive.
+    pub fn between<'a>(
+        &'a self,
+        from: &'a str,
+        to: &'a str,
+    ) -> impl Iterator<Item = &'a SessionEntry> {
+        self.sessions
+            .iter()
+            .filter(move |session| from <= session.date.as_str() && session.date.as_str() <= to)
+    }
+
+    /// The XP gained across all sessions, saturating at `u32::MAX`.
+    pub fn total_xp(&self) -> u32 {
+        self.sessions
+            .iter()
+            .map(|session| session.xp_gained)
+            .fold(0, u32::saturating_add)
+    }
+
+    /// The GM intrusions accepted across all sessions, saturating at
+    /// `u32::MAX`.
+    pub fn total_intrusions(&self) -> u32 {
+        self.sessions
+            .iter()
+            .map(|session| session.intrusions_accepted)
+            .fold(0, u32::saturating_add)
+    }
+
+    /// Every cypher found across all sessions.
+    pub fn cyphers_found(&self) -> impl Iterator<Item = &Cypher> {
+        self.sessions
+            .iter()
+            .flat_map(|session| &session.cyphers_found)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cypher::CypherForm;
+    use crate::id::CypherId;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn log_summarizes_sessions() {
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut log = SessionLog::new();
+        log.append(SessionEntry {
+            xp_gained: 3,
+            intrusions_accepted: 1,
+            cyphers_found: vec![Cypher {
+                id: Some(CypherId::random(&mut rng)),
+                name: "Stim".to_string(),
+                level: 4,
+                form: CypherForm::Manifest,
+            }],
+            ..SessionEntry::new("2024-03-02")
+        });
+        log.append(SessionEntry {
+            xp_gained: 2,
+            intrusions_accepted: 2,
+            cyphers_used: vec!["Stim".to_string()],
+            ..SessionEntry::new("2024-03-09")
+        });
+        log.append(SessionEntry::new("2024-04-06"));
+
+        assert_eq!(log.total_xp(), 5);
+        assert_eq!(log.total_intrusions(), 3);
+        assert_eq!(log.cyphers_found().count(), 1);
+        assert_eq!(log.between("2024-03-01", "2024-03-31").count(), 2);
+        assert_eq!(log.latest().unwrap().date, "2024-04-06");
+    }
+
+    #[test]
+    fn totals_saturate() {
+        let mut log = SessionLog::new();
+        for xp in [u32::MAX, 1] {
+            log.append(SessionEntry {
+                xp_gained: xp,
+                intrusions_accepted: xp,
+                ..SessionEntry::new("2024-03-02")
+            });
+        }
+        assert_eq!(log.total_xp(), u32::MAX);
+        assert_eq!(log.total_intrusions(), u32::MAX);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn log_round_trips_through_json() {
+        let mut log = SessionLog::new();
+        log.append(SessionEntry {
+            xp_gained: 2,
+            notes: "Found the vault".to_string(),
+            ..SessionEntry::new("2024-03-02")
+        });
+
+        let json = serde_json::to_string(&log).unwrap();
+        assert!(json.starts_with('['));
+        assert_eq!(serde_json::from_str::<SessionLog>(&json).unwrap(), log);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn loads_cyphers_saved_without_ids() {
+        let json = r#"[{
+            "date": "2024-03-02",
+            "xp_gained": 1,
+            "intrusions_accepted": 0,
+            "cyphers_found": [{"name": "Stim", "level": 4, "form": "Manifest"}],
+            "cyphers_used": [],
+            "notes": ""
+        }]"#;
+
+        let log: SessionLog = serde_json::from_str(json).unwrap();
+        let cypher = log.cyphers_found().next().unwrap();
+        assert_eq!(cypher.name, "Stim");
+        assert_eq!(cypher.id, None);
+    }
+}
@@ -0,0 +1,47 @@
+//! How skilled a character is at a kind of task.
+
+/// A character's level of skill at a kind of task.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Training {
+    /// Tasks of this kind are hindered by one step.
+    Inability,
+    #[default]
+    Untrained,
+    /// Tasks of this kind are eased by one step.
+    Trained,
+    /// Tasks of this kind are eased by two steps.
+    Specialized,
+}
+
+impl Training {
+    /// The change in difficulty steps for a task of this kind. Positive
+    /// values hinder the task, negative values ease it.
+    pub fn difficulty_modifier(self) -> i8 {
+        match self {
+            Training::Inability => 1,
+            Training::Untrained => 0,
+            Training::Trained => -1,
+            Training::Specialized => -2,
+        }
+    }
+
+    /// The difficulty of a task of the given level for a character with
+    /// this training, kept within the usual range of 0 to 10.
+    pub fn adjust(self, level: u8) -> u8 {
+        let difficulty = i16::from(level) + i16::from(self.difficulty_modifier());
+        difficulty.clamp(0, 10) as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adjusted_difficulty_stays_in_range() {
+        assert_eq!(Training::Trained.adjust(3), 2);
+        assert_eq!(Training::Specialized.adjust(1), 0);
+        assert_eq!(Training::Inability.adjust(10), 10);
+        assert_eq!(Training::Untrained.adjust(200), 10);
+    }
+}
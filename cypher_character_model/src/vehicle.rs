@@ -0,0 +1,169 @@
+//! Vehicles for modern and science fiction campaigns.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::damage::{self, DamageScale};
+use crate::id::VehicleId;
+use crate::range::Range;
+use crate::skill::Training;
+
+/// How fast a vehicle travels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum VehicleSpeed {
+    Slow,
+    Average,
+    Fast,
+    VeryFast,
+}
+
+/// A weapon mounted on a vehicle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct WeaponSystem {
+    pub name: String,
+    /// The damage the weapon deals, at vehicle scale.
+    pub damage: u8,
+    pub range: Range,
+}
+
+impl WeaponSystem {
+    pub fn new(name: impl Into<String>, damage: u8, range: Range) -> Self {
+        Self {
+            name: name.into(),
+            damage,
+            range,
+        }
+    }
+
+    /// The damage the weapon deals, in the given scale.
+    pub fn damage_in(&self, scale: DamageScale) -> u8 {
+        DamageScale::Vehicle.convert(self.damage, scale)
+    }
+}
+
+/// A vehicle's stats.
+///
+/// A vehicle's Armor and health are at vehicle scale, where one point is
+/// worth [`VEHICLE_SCALE`](crate::damage::VEHICLE_SCALE) points of personal
+/// damage.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Vehicle {
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub id: Option<VehicleId>,
+    pub name: String,
+    pub level: u8,
+    pub speed: VehicleSpeed,
+    /// The number of crew needed to operate the vehicle.
+    pub crew: u8,
+    pub armor: u8,
+    pub health: u8,
+    #[cfg_attr(feature = "serde", serde(alias = "maxHealth"))]
+    pub max_health: u8,
+    pub weapons: Vec<WeaponSystem>,
+}
+
+impl Vehicle {
+    /// Creates a vehicle with health equal to its target number and a
+    /// single pilot.
+    pub fn new(name: impl Into<String>, level: u8, speed: VehicleSpeed) -> Self {
+        let max_health = level.saturating_mul(3);
+        Self {
+            id: None,
+            name: name.into(),
+            level,
+            speed,
+            crew: 1,
+            armor: 0,
+            health: max_health,
+            max_health,
+            weapons: Vec::new(),
+        }
+    }
+
+    pub fn with_id(mut self, id: VehicleId) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn with_crew(mut self, crew: u8) -> Self {
+        self.crew = crew;
+        self
+    }
+
+    pub fn with_armor(mut self, armor: u8) -> Self {
+        self.armor = armor;
+        self
+    }
+
+    pub fn with_weapon(mut self, weapon: WeaponSystem) -> Self {
+        self.weapons.push(weapon);
+        self
+    }
+
+    /// The difficulty of a piloting task for this vehicle, adjusted by the
+    /// pilot's training in piloting.
+    pub fn piloting_difficulty(&self, training: Training) -> u8 {
+        training.adjust(self.level)
+    }
+
+    /// Deals damage in the given scale to the vehicle. The damage is
+    /// converted to vehicle scale and then reduced by the vehicle's Armor.
+    /// Returns the vehicle-scale damage actually taken.
+    pub fn take_damage(&mut self, damage: u8, scale: DamageScale) -> u8 {
+        let damage = scale.convert(damage, DamageScale::Vehicle);
+        damage::apply(&mut self.health, self.armor, damage)
+    }
+
+    /// Whether the vehicle has taken enough damage to stop working.
+    pub fn is_disabled(&self) -> bool {
+        self.health == 0
+    }
+
+    /// The weapons able to reach a target at the given range.
+    pub fn weapons_in_range(&self, range: Range) -> impl Iterator<Item = &WeaponSystem> {
+        self.weapons
+            .iter()
+            .filter(move |weapon| range.is_within(weapon.range))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn training_eases_piloting() {
+        let vehicle = Vehicle::new("Hover skiff", 4, VehicleSpeed::Fast);
+        assert_eq!(vehicle.piloting_difficulty(Training::Untrained), 4);
+        assert_eq!(vehicle.piloting_difficulty(Training::Specialized), 2);
+        assert_eq!(vehicle.piloting_difficulty(Training::Inability), 5);
+    }
+
+    #[test]
+    fn armor_and_weapon_ranges() {
+        let mut vehicle = Vehicle::new("Gunship", 5, VehicleSpeed::VeryFast)
+            .with_armor(3)
+            .with_weapon(WeaponSystem::new("Cannon", 10, Range::VeryLong))
+            .with_weapon(WeaponSystem::new("Flamer", 6, Range::Short));
+
+        assert_eq!(vehicle.weapons_in_range(Range::Long).count(), 1);
+        assert_eq!(vehicle.take_damage(2, DamageScale::Vehicle), 0);
+        assert_eq!(vehicle.take_damage(18, DamageScale::Vehicle), 15);
+        assert!(vehicle.is_disabled());
+    }
+
+    #[test]
+    fn damage_crosses_scales() {
+        let mut vehicle = Vehicle::new("Hover skiff", 4, VehicleSpeed::Fast).with_armor(1);
+        assert_eq!(vehicle.take_damage(15, DamageScale::Personal), 0);
+        assert_eq!(vehicle.take_damage(40, DamageScale::Personal), 3);
+        assert_eq!(vehicle.health, 9);
+
+        let cannon = WeaponSystem::new("Cannon", 2, Range::Long);
+        assert_eq!(cannon.damage_in(DamageScale::Personal), 20);
+        assert_eq!(cannon.damage_in(DamageScale::Vehicle), 2);
+    }
+}
@@ -0,0 +1,177 @@
+//! Setting-agnostic wealth: abstract price categories, or discrete currency
+//! recorded in a [`Ledger`].
+
+use std::error::Error;
+use std::fmt;
+
+use crate::ledger::{Ledger, Resource};
+
+/// The ledger account that currency spent on purchases is transferred to.
+pub const MARKET_ACCOUNT: &str = "market";
+
+/// An abstract price category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PriceCategory {
+    Inexpensive,
+    Moderate,
+    Expensive,
+    VeryExpensive,
+    Exorbitant,
+}
+
+impl fmt::Display for PriceCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            PriceCategory::Inexpensive => "inexpensive",
+            PriceCategory::Moderate => "moderately priced",
+            PriceCategory::Expensive => "expensive",
+            PriceCategory::VeryExpensive => "very expensive",
+            PriceCategory::Exorbitant => "exorbitant",
+        };
+        f.write_str(name)
+    }
+}
+
+/// The price of an item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Price {
+    Category(PriceCategory),
+    Amount(u64),
+}
+
+/// What a character can afford.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Wealth {
+    /// The character can freely buy items up to the given price category.
+    Abstract(PriceCategory),
+    /// The character holds the named currency. How much they hold is their
+    /// balance of [`Resource::Currency`] in a [`Ledger`].
+    Currency(String),
+}
+
+/// Why a purchase could not be made.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PurchaseError {
+    /// The price is more than the character can afford.
+    CannotAfford,
+    /// The price is given in a different form to the character's wealth,
+    /// such as a category price against a currency amount.
+    IncompatiblePrice,
+}
+
+impl fmt::Display for PurchaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PurchaseError::CannotAfford => f.write_str("the character cannot afford this"),
+            PurchaseError::IncompatiblePrice => {
+                f.write_str("the price is not in the same form as the character's wealth")
+            }
+        }
+    }
+}
+
+impl Error for PurchaseError {}
+
+impl Wealth {
+    /// Wealth held in the named currency.
+    pub fn currency(unit: impl Into<String>) -> Self {
+        Wealth::Currency(unit.into())
+    }
+
+    /// The ledger resource that currency wealth is recorded as, or `None`
+    /// for abstract wealth.
+    pub fn resource(&self) -> Option<Resource> {
+        match self {
+            Wealth::Abstract(_) => None,
+            Wealth::Currency(unit) => Some(Resource::Currency(unit.clone())),
+        }
+    }
+
+    /// Checks whether the given account can pay the given price.
+    pub fn check(&self, price: Price, ledger: &Ledger, account: &str) -> Result<(), PurchaseError> {
+        match (self, price) {
+            (Wealth::Abstract(limit), Price::Category(category)) if category <= *limit => Ok(()),
+            (Wealth::Abstract(_), Price::Category(_)) => Err(PurchaseError::CannotAfford),
+            (Wealth::Currency(unit), Price::Amount(cost)) => {
+                let balance = ledger.balance(account, &Resource::Currency(unit.clone()));
+                if i128::from(cost) <= balance {
+                    Ok(())
+                } else {
+                    Err(PurchaseError::CannotAfford)
+                }
+            }
+            _ => Err(PurchaseError::IncompatiblePrice),
+        }
+    }
+
+    pub fn can_afford(&self, price: Price, ledger: &Ledger, account: &str) -> bool {
+        self.check(price, ledger, account).is_ok()
+    }
+
+    /// Pays the given price for an item. Currency is transferred from the
+    /// account to [`MARKET_ACCOUNT`] in the ledger, with the item as the
+    /// reason. Abstract wealth records nothing for purchases it can afford.
+    pub fn purchase(
+        &self,
+        price: Price,
+        ledger: &mut Ledger,
+        account: &str,
+        item: impl Into<String>,
+    ) -> Result<(), PurchaseError> {
+        self.check(price, ledger, account)?;
+        if let (Some(resource), Price::Amount(cost)) = (self.resource(), price) {
+            ledger
+                .transfer(account, MARKET_ACCOUNT, resource, cost, item)
+                .map_err(|_| PurchaseError::CannotAfford)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn abstract_wealth_affords_up_to_its_category() {
+        let mut ledger = Ledger::new();
+        let wealth = Wealth::Abstract(PriceCategory::Expensive);
+        assert!(wealth.can_afford(Price::Category(PriceCategory::Moderate), &ledger, "Kira"));
+        assert_eq!(
+            wealth.purchase(
+                Price::Category(PriceCategory::Exorbitant),
+                &mut ledger,
+                "Kira",
+                "airship"
+            ),
+            Err(PurchaseError::CannotAfford)
+        );
+        assert_eq!(
+            wealth.purchase(Price::Amount(10), &mut ledger, "Kira", "rope"),
+            Err(PurchaseError::IncompatiblePrice)
+        );
+        assert!(ledger.entries().is_empty());
+    }
+
+    #[test]
+    fn currency_purchases_are_ledger_transfers() {
+        let mut ledger = Ledger::new();
+        let shins = Resource::Currency("shins".to_string());
+        ledger.grant("loot", "Kira", shins.clone(), 20, "found in the ruin");
+
+        let wealth = Wealth::currency("shins");
+        assert_eq!(
+            wealth.purchase(Price::Amount(15), &mut ledger, "Kira", "rope"),
+            Ok(())
+        );
+        assert_eq!(ledger.balance("Kira", &shins), 5);
+        assert_eq!(ledger.balance(MARKET_ACCOUNT, &shins), 15);
+        assert_eq!(ledger.history("Kira").last().unwrap().reason, "rope");
+
+        assert_eq!(
+            wealth.purchase(Price::Amount(6), &mut ledger, "Kira", "lantern"),
+            Err(PurchaseError::CannotAfford)
+        );
+        assert_eq!(ledger.entries().len(), 2);
+    }
+}
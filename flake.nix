@@ -29,6 +29,7 @@
 
         rustToolchain = pkgs.rust-bin.stable.latest.default.override {
           extensions = [ "rust-analyzer" "rust-src" ];
+          targets = [ "wasm32-unknown-unknown" ];
         };
 
         craneLib = (crane.mkLib pkgs).overrideToolchain rustToolchain;
@@ -48,6 +49,14 @@
         cypher_character_creator = craneLib.buildPackage (commonArgs // {
           inherit cargoArtifacts;
         });
+
+        # The model crate must keep building for browsers
+        wasmArgs = commonArgs // {
+          pnameSuffix = "-wasm";
+          CARGO_BUILD_TARGET = "wasm32-unknown-unknown";
+          cargoExtraArgs = "--package cypher_character_model --all-features";
+          doCheck = false;
+        };
       in
       rec {
         checks = {
@@ -64,6 +73,11 @@
           fmt = craneLib.cargoFmt (commonArgs // {
             inherit src;
           });
+
+          wasm = craneLib.mkCargoDerivation (wasmArgs // {
+            cargoArtifacts = craneLib.buildDepsOnly wasmArgs;
+            buildPhaseCargoCommand = "cargo check --release --target wasm32-unknown-unknown $cargoExtraArgs";
+          });
         };
 
         packages.cypher_character_creator = cypher_character_creator;